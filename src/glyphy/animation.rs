@@ -0,0 +1,121 @@
+use super::hsl_to_normalized_rgba;
+
+/// State that advances with time. Call `update` once per frame with the
+/// seconds elapsed since the last one.
+pub trait Animation {
+    fn update(&mut self, dt: f32);
+}
+
+/// Cycles each character of a string through the hue wheel, with the whole
+/// spectrum spread across the string once. Draw it with
+/// `Glyphy::draw_rainbow_text`.
+pub struct RainbowAnimation {
+    /// Full trips around the hue wheel per second.
+    pub speed_hz: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+    elapsed: f32,
+}
+
+impl RainbowAnimation {
+    pub fn new(speed_hz: f32, saturation: f32, lightness: f32) -> Self {
+        Self {
+            speed_hz,
+            saturation,
+            lightness,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Hue in degrees of character `i` of `char_count`.
+    pub fn hue(&self, i: usize, char_count: usize) -> f32 {
+        let hue = (i as f32 / char_count as f32) * 360.0 + self.elapsed * self.speed_hz * 360.0;
+        hue.rem_euclid(360.0)
+    }
+
+    /// Normalized colour of each of `char_count` characters.
+    pub fn colors(&self, char_count: usize) -> Vec<[f32; 4]> {
+        (0..char_count)
+            .map(|i| {
+                hsl_to_normalized_rgba(self.hue(i, char_count), self.saturation, self.lightness)
+            })
+            .collect()
+    }
+}
+
+impl Animation for RainbowAnimation {
+    fn update(&mut self, dt: f32) {
+        self.elapsed += dt;
+    }
+}
+
+/// Pulls a position towards `target` like a damped spring, integrated with
+/// semi-implicit Euler on each `update`.
+pub struct SpringAnimation {
+    pub target: (f32, f32),
+    pub stiffness: f32,
+    pub damping: f32,
+    position: (f32, f32),
+    velocity: (f32, f32),
+}
+
+impl SpringAnimation {
+    /// A spring at rest at `position`, heading for `target`.
+    pub fn new(position: (f32, f32), target: (f32, f32), stiffness: f32, damping: f32) -> Self {
+        Self {
+            target,
+            stiffness,
+            damping,
+            position,
+            velocity: (0.0, 0.0),
+        }
+    }
+
+    pub fn current_position(&self) -> (f32, f32) {
+        self.position
+    }
+}
+
+impl Animation for SpringAnimation {
+    fn update(&mut self, dt: f32) {
+        let step = |position: f32, velocity: f32, target: f32| {
+            let velocity =
+                velocity + (target - position) * self.stiffness * dt - velocity * self.damping * dt;
+            (position + velocity * dt, velocity)
+        };
+
+        let (x, vx) = step(self.position.0, self.velocity.0, self.target.0);
+        let (y, vy) = step(self.position.1, self.velocity.1, self.target.1);
+        self.position = (x, y);
+        self.velocity = (vx, vy);
+    }
+}
+
+#[test]
+fn test_rainbow_adjacent_chars_differ() {
+    let colors = RainbowAnimation::new(1.0, 1.0, 0.5).colors(5);
+    for pair in colors.windows(2) {
+        assert_ne!(pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn test_rainbow_cycles_with_time() {
+    let mut rainbow = RainbowAnimation::new(0.5, 1.0, 0.5);
+    assert_eq!(rainbow.hue(1, 4), 90.0);
+    rainbow.update(0.5);
+    assert_eq!(rainbow.hue(1, 4), 180.0);
+    rainbow.update(1.5);
+    assert_eq!(rainbow.hue(1, 4), 90.0);
+}
+
+#[test]
+fn test_spring_converges_to_target() {
+    let mut spring = SpringAnimation::new((0.0, 0.0), (100.0, -50.0), 120.0, 12.0);
+    for _ in 0..300 {
+        spring.update(1.0 / 60.0);
+    }
+
+    let (x, y) = spring.current_position();
+    assert!((x - 100.0).abs() < 0.01 && (y + 50.0).abs() < 0.01);
+}
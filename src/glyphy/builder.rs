@@ -0,0 +1,215 @@
+use super::{FontInfo, FontSource, Glyphy, GlyphyError};
+use std::path::PathBuf;
+use wgpu_glyph::ab_glyph::FontArc;
+
+/// glyph_brush's default glyph atlas size.
+const DEFAULT_ATLAS_SIZE: (u32, u32) = (256, 256);
+pub(crate) const MIN_ATLAS_DIMENSION: u32 = 64;
+
+enum FontData {
+    Bytes(Vec<u8>),
+    Path(PathBuf),
+}
+
+/// Configures a `Glyphy` before it is created. `Glyphy::init` is the same
+/// as `GlyphyBuilder::new().build(..)`.
+pub struct GlyphyBuilder {
+    atlas_size: (u32, u32),
+    #[cfg_attr(not(feature = "bundled-font"), allow(dead_code))]
+    embedded_font: bool,
+    fonts: Vec<FontData>,
+}
+
+impl GlyphyBuilder {
+    pub fn new() -> Self {
+        Self {
+            atlas_size: DEFAULT_ATLAS_SIZE,
+            embedded_font: true,
+            fonts: vec![],
+        }
+    }
+
+    /// Initial size of the glyph texture atlas. Larger atlases hold more
+    /// glyphs, such as CJK text or many font sizes, before the cache has to
+    /// be rebuilt. Both dimensions must be powers of two from 64 up to the
+    /// device's `max_texture_dimension_2d`, or `build` returns
+    /// `GlyphyError::InvalidAtlasSize`.
+    pub fn atlas_size(mut self, width: u32, height: u32) -> Self {
+        self.atlas_size = (width, height);
+        self
+    }
+
+    /// Skips loading the bundled Inconsolata, so the first added font
+    /// becomes `FontId(0)`. Building without the `bundled-font` feature
+    /// leaves it out regardless.
+    pub fn no_embedded_font(mut self) -> Self {
+        self.embedded_font = false;
+        self
+    }
+
+    /// Adds a font from its TTF or OTF bytes. Fonts get `FontId`s in the
+    /// order they are added, after the bundled font if it is loaded.
+    pub fn font_bytes(mut self, bytes: Vec<u8>) -> Self {
+        self.fonts.push(FontData::Bytes(bytes));
+        self
+    }
+
+    /// Adds a font read from `path` when `build` is called.
+    pub fn font_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.fonts.push(FontData::Path(path.into()));
+        self
+    }
+
+    /// Builds a `Glyphy` that draws into textures of `format`.
+    ///
+    /// Text colours reach the render target unchanged: wgpu_glyph's shader
+    /// does no colour conversion. With an `*Srgb` format, such as the
+    /// `Bgra8UnormSrgb` main.rs usually gets, the GPU treats shader output
+    /// as linear and encodes it to sRGB on write. So colours should be
+    /// linear, and values from `hex_str_to_normalized_rgba` come out lighter
+    /// than the hex code unless passed through `srgb_to_linear` first. With
+    /// a non-sRGB format, such as `Bgra8Unorm`, values are written as they
+    /// are, so sRGB-encoded colours like hex codes are correct as they stand.
+    pub fn build(
+        self,
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+    ) -> Result<Glyphy, GlyphyError> {
+        let atlas_size = self.atlas_size;
+        check_atlas_size(atlas_size, device.limits().max_texture_dimension_2d)?;
+        let (fonts, font_infos) = self.load_fonts()?;
+
+        Ok(Glyphy::with_fonts(
+            device, format, fonts, font_infos, atlas_size,
+        ))
+    }
+
+    // Parses every configured font, in FontId order.
+    fn load_fonts(self) -> Result<(Vec<FontArc>, Vec<FontInfo>), GlyphyError> {
+        let mut fonts = vec![];
+        let mut font_infos = vec![];
+
+        #[cfg(feature = "bundled-font")]
+        if self.embedded_font {
+            let inconsolata_bytes = include_bytes!("Inconsolata-Regular.ttf");
+            fonts.push(FontArc::try_from_slice(inconsolata_bytes)?);
+            font_infos.push(FontInfo::from_bytes(
+                inconsolata_bytes,
+                FontSource::Embedded,
+            ));
+        }
+
+        for font in self.fonts {
+            let (bytes, source) = match font {
+                FontData::Bytes(bytes) => (bytes, FontSource::Embedded),
+                FontData::Path(path) => (std::fs::read(&path)?, FontSource::FilePath(path)),
+            };
+            font_infos.push(FontInfo::from_bytes(&bytes, source));
+            fonts.push(FontArc::try_from_vec(bytes)?);
+        }
+
+        if fonts.is_empty() {
+            return Err(GlyphyError::NoFontConfigured);
+        }
+
+        Ok((fonts, font_infos))
+    }
+}
+
+impl Default for GlyphyBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fails unless both dimensions of `size` are powers of two in
+/// `64..=max_dimension`.
+pub(crate) fn check_atlas_size(size: (u32, u32), max_dimension: u32) -> Result<(), GlyphyError> {
+    for dimension in [size.0, size.1] {
+        if !dimension.is_power_of_two()
+            || !(MIN_ATLAS_DIMENSION..=max_dimension).contains(&dimension)
+        {
+            return Err(GlyphyError::InvalidAtlasSize {
+                requested: size,
+                max: max_dimension,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Shrinks each dimension of a valid atlas `size` to the largest power of
+/// two no greater than `max_dimension`.
+pub(crate) fn clamp_atlas_size(size: (u32, u32), max_dimension: u32) -> (u32, u32) {
+    let max = 1 << (u32::BITS - 1 - max_dimension.leading_zeros());
+    (size.0.min(max), size.1.min(max))
+}
+
+#[test]
+fn test_no_font_configured() {
+    assert!(matches!(
+        GlyphyBuilder::new().no_embedded_font().load_fonts(),
+        Err(GlyphyError::NoFontConfigured)
+    ));
+}
+
+#[test]
+fn test_font_bytes_without_embedded_font() {
+    let (fonts, infos) = GlyphyBuilder::new()
+        .no_embedded_font()
+        .font_bytes(include_bytes!("Inconsolata-Regular.ttf").to_vec())
+        .load_fonts()
+        .unwrap();
+    assert_eq!(fonts.len(), 1);
+    assert_eq!(infos[0].family_name, "Inconsolata");
+}
+
+#[test]
+fn test_missing_font_path() {
+    assert!(matches!(
+        GlyphyBuilder::new()
+            .font_path("does-not-exist.ttf")
+            .load_fonts(),
+        Err(GlyphyError::Io(_))
+    ));
+}
+
+#[test]
+fn test_check_atlas_size() {
+    assert!(check_atlas_size(DEFAULT_ATLAS_SIZE, 8192).is_ok());
+    assert!(check_atlas_size((2048, 64), 2048).is_ok());
+}
+
+#[test]
+fn test_atlas_size_not_power_of_two() {
+    assert!(matches!(
+        check_atlas_size((1000, 1024), 8192),
+        Err(GlyphyError::InvalidAtlasSize {
+            requested: (1000, 1024),
+            max: 8192
+        })
+    ));
+}
+
+#[test]
+fn test_atlas_size_too_small() {
+    assert!(matches!(
+        check_atlas_size((32, 32), 8192),
+        Err(GlyphyError::InvalidAtlasSize { .. })
+    ));
+}
+
+#[test]
+fn test_atlas_size_over_device_limit() {
+    assert!(matches!(
+        check_atlas_size((4096, 4096), 2048),
+        Err(GlyphyError::InvalidAtlasSize { .. })
+    ));
+}
+
+#[test]
+fn test_clamp_atlas_size() {
+    assert_eq!(clamp_atlas_size((4096, 256), 2048), (2048, 256));
+    assert_eq!(clamp_atlas_size((4096, 4096), 3000), (2048, 2048));
+    assert_eq!(clamp_atlas_size((1024, 1024), 8192), (1024, 1024));
+}
@@ -0,0 +1,46 @@
+/// WCAG 2.1 relative luminance of a normalized sRGB colour. Alpha is
+/// ignored.
+fn relative_luminance(color: [f32; 4]) -> f32 {
+    let linear = |c: f32| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color[0]) + 0.7152 * linear(color[1]) + 0.0722 * linear(color[2])
+}
+
+/// WCAG 2.1 contrast ratio between two normalized sRGB colours, from 1.0 for
+/// identical luminance to 21.0 for black on white. Alpha is ignored, so
+/// blend translucent text over its background first.
+pub fn wcag_contrast_ratio(text_color: [f32; 4], bg_color: [f32; 4]) -> f32 {
+    let (a, b) = (relative_luminance(text_color), relative_luminance(bg_color));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Whether the colours meet WCAG AA: 4.5:1 for normal text, or 3:1 for
+/// large text (18pt, or 14pt bold).
+pub fn meets_wcag_aa(text_color: [f32; 4], bg_color: [f32; 4], large_text: bool) -> bool {
+    let required = if large_text { 3.0 } else { 4.5 };
+    wcag_contrast_ratio(text_color, bg_color) >= required
+}
+
+#[test]
+fn test_wcag_contrast_ratio() {
+    let black = [0.0, 0.0, 0.0, 1.0];
+    let white = [1.0, 1.0, 1.0, 1.0];
+    assert!((wcag_contrast_ratio(black, white) - 21.0).abs() < 1e-4);
+    assert!((wcag_contrast_ratio(white, black) - 21.0).abs() < 1e-4);
+    assert_eq!(wcag_contrast_ratio(white, white), 1.0);
+}
+
+#[test]
+fn test_meets_wcag_aa() {
+    let white = [1.0, 1.0, 1.0, 1.0];
+    // #777777 on white is about 4.48:1.
+    let grey = super::hex_str_to_normalized_rgba("#777777");
+    assert!(!meets_wcag_aa(grey, white, false));
+    assert!(meets_wcag_aa(grey, white, true));
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+use wgpu_glyph::ab_glyph::InvalidFont;
+
+#[derive(Debug)]
+pub enum GlyphyError {
+    /// Font bytes, bundled or added to `GlyphyBuilder`, could not be parsed.
+    InvalidFont(InvalidFont),
+    /// A font file could not be read.
+    Io(std::io::Error),
+    /// `GlyphyBuilder::build` was called with no fonts: the bundled font was
+    /// turned off and none were added.
+    NoFontConfigured,
+    /// `GlyphyBuilder::atlas_size` was given a dimension that is not a power
+    /// of two from 64 up to the device's `max_texture_dimension_2d`.
+    InvalidAtlasSize { requested: (u32, u32), max: u32 },
+}
+
+impl fmt::Display for GlyphyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // The wrapped errors are reported through `source`.
+            GlyphyError::InvalidFont(_) => write!(f, "invalid font data"),
+            GlyphyError::Io(_) => write!(f, "unable to read font"),
+            GlyphyError::NoFontConfigured => write!(f, "no font configured"),
+            GlyphyError::InvalidAtlasSize { requested, max } => write!(
+                f,
+                "atlas size {}x{} must be powers of two between {} and {}",
+                requested.0,
+                requested.1,
+                super::builder::MIN_ATLAS_DIMENSION,
+                max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GlyphyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GlyphyError::InvalidFont(e) => Some(e),
+            GlyphyError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InvalidFont> for GlyphyError {
+    fn from(e: InvalidFont) -> Self {
+        GlyphyError::InvalidFont(e)
+    }
+}
+
+impl From<std::io::Error> for GlyphyError {
+    fn from(e: std::io::Error) -> Self {
+        GlyphyError::Io(e)
+    }
+}
+
+#[test]
+fn test_source_is_wrapped_error() {
+    use std::error::Error;
+
+    let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.ttf");
+    let error = GlyphyError::from(io);
+    assert_eq!(error.to_string(), "unable to read font");
+    assert_eq!(error.source().unwrap().to_string(), "missing.ttf");
+    assert!(GlyphyError::NoFontConfigured.source().is_none());
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+use ttf_parser::{name_id, Face};
+
+/// Where a font loaded by `Glyphy` came from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FontSource {
+    /// Supplied as bytes, like the bundled Inconsolata.
+    Embedded,
+    FilePath(PathBuf),
+}
+
+/// Names of a loaded font, read from its `name` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontInfo {
+    /// Empty if the font has no Unicode family name.
+    pub family_name: String,
+    /// Empty if the font has no Unicode PostScript name.
+    pub postscript_name: String,
+    pub source: FontSource,
+}
+
+impl FontInfo {
+    pub fn from_bytes(data: &[u8], source: FontSource) -> Self {
+        let face = Face::from_slice(data, 0).ok();
+        let name = |id| {
+            face.as_ref()
+                .and_then(|face| {
+                    face.names()
+                        .into_iter()
+                        .filter(|name| name.name_id == id)
+                        .find_map(|name| name.to_string())
+                })
+                .unwrap_or_default()
+        };
+
+        Self {
+            family_name: name(name_id::FAMILY),
+            postscript_name: name(name_id::POST_SCRIPT_NAME),
+            source,
+        }
+    }
+}
+
+#[test]
+fn test_inconsolata_names() {
+    let info = FontInfo::from_bytes(
+        include_bytes!("Inconsolata-Regular.ttf"),
+        FontSource::Embedded,
+    );
+    assert_eq!(info.family_name, "Inconsolata");
+    assert_eq!(info.postscript_name, "Inconsolata-Regular");
+    assert_eq!(info.source, FontSource::Embedded);
+}
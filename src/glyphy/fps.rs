@@ -0,0 +1,52 @@
+/// Colours for `Glyphy::draw_fps_counter`. At or above `thresholds.0` the
+/// counter is `good`, at or above `thresholds.1` it is `ok`, and below that
+/// it is `bad`.
+pub struct FpsCounterStyle {
+    pub good: [f32; 4],
+    pub ok: [f32; 4],
+    pub bad: [f32; 4],
+    pub thresholds: (f32, f32),
+}
+
+impl FpsCounterStyle {
+    pub fn color(&self, fps: f32) -> [f32; 4] {
+        if fps >= self.thresholds.0 {
+            self.good
+        } else if fps >= self.thresholds.1 {
+            self.ok
+        } else {
+            self.bad
+        }
+    }
+}
+
+/// Green from 60 fps, yellow from 30, red below.
+impl Default for FpsCounterStyle {
+    fn default() -> Self {
+        Self {
+            good: [0.0, 1.0, 0.0, 1.0],
+            ok: [1.0, 1.0, 0.0, 1.0],
+            bad: [1.0, 0.0, 0.0, 1.0],
+            thresholds: (60.0, 30.0),
+        }
+    }
+}
+
+pub(crate) fn fps_text(fps: f32) -> String {
+    format!("FPS: {:.0}", fps)
+}
+
+#[test]
+fn test_fps_counter_colors() {
+    let style = FpsCounterStyle::default();
+    assert_eq!(style.color(144.0), style.good);
+    assert_eq!(style.color(60.0), style.good);
+    assert_eq!(style.color(59.9), style.ok);
+    assert_eq!(style.color(30.0), style.ok);
+    assert_eq!(style.color(12.0), style.bad);
+}
+
+#[test]
+fn test_fps_text() {
+    assert_eq!(fps_text(59.6), "FPS: 60");
+}
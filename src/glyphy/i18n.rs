@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+/// Replaces each `{name}` in `template` with `args["name"]`. Placeholders
+/// with no matching argument are left as they are, and arguments with no
+/// placeholder are ignored.
+pub fn format_message(template: &str, args: &HashMap<&str, &str>) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        message.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // A later `{` starts the placeholder instead; this one is literal.
+        match rest[1..].find(['{', '}']).map(|i| i + 1) {
+            Some(next) if rest.as_bytes()[next] == b'{' => {
+                message.push_str(&rest[..next]);
+                rest = &rest[next..];
+            }
+            Some(end) => {
+                let placeholder = &rest[..=end];
+                match args.get(&placeholder[1..end]) {
+                    Some(value) => message.push_str(value),
+                    None => message.push_str(placeholder),
+                }
+                rest = &rest[end + 1..];
+            }
+            None => break,
+        }
+    }
+
+    message.push_str(rest);
+    message
+}
+
+#[test]
+fn test_format_message() {
+    let args = HashMap::from([("name", "Ada"), ("count", "3"), ("unused", "x")]);
+    assert_eq!(
+        format_message("{name} has {count} new messages", &args),
+        "Ada has 3 new messages"
+    );
+}
+
+#[test]
+fn test_format_message_missing_key() {
+    let args = HashMap::from([("name", "Ada")]);
+    assert_eq!(
+        format_message("{greeting}, {name}! {", &args),
+        "{greeting}, Ada! {"
+    );
+    assert_eq!(format_message("Hi { there {name}", &args), "Hi { there Ada");
+}
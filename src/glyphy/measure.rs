@@ -0,0 +1,73 @@
+use wgpu_glyph::{
+    ab_glyph::{Font, ScaleFont},
+    FontId, GlyphPositioner, Layout, SectionGeometry, SectionText,
+};
+
+/// Width of the widest line of `text` in the first of `fonts` at `scale`,
+/// and the height of all its lines. Lines are split on `\n` and, if
+/// `max_width` is given, wrapped at word boundaries to fit it.
+pub(crate) fn measure_multiline<F: Font>(
+    fonts: &[F],
+    text: &str,
+    scale: f32,
+    max_width: Option<f32>,
+) -> (f32, f32) {
+    let scaled = fonts[0].as_scaled(scale);
+    let line_height = scaled.ascent() - scaled.descent() + scaled.line_gap();
+    let geometry = SectionGeometry {
+        screen_position: (0.0, 0.0),
+        bounds: (max_width.unwrap_or(f32::INFINITY), f32::INFINITY),
+    };
+
+    let mut width: f32 = 0.0;
+    let mut line_count = 0;
+    for line in text.split('\n') {
+        let glyphs = Layout::default_wrap().calculate_glyphs(
+            fonts,
+            &geometry,
+            &[SectionText {
+                text: line,
+                scale: scale.into(),
+                font_id: FontId(0),
+            }],
+        );
+
+        // Each wrapped row sits one line height below the last.
+        let mut rows = 1;
+        for g in &glyphs {
+            let right = g.glyph.position.x + scaled.h_advance(g.glyph.id);
+            width = width.max(right);
+            let row = ((g.glyph.position.y - scaled.ascent()) / line_height).round() as usize;
+            rows = rows.max(row + 1);
+        }
+        line_count += rows;
+    }
+
+    (width, line_count as f32 * line_height)
+}
+
+#[cfg(test)]
+fn inconsolata() -> wgpu_glyph::ab_glyph::FontRef<'static> {
+    wgpu_glyph::ab_glyph::FontRef::try_from_slice(include_bytes!("Inconsolata-Regular.ttf"))
+        .unwrap()
+}
+
+#[test]
+fn test_two_lines_are_twice_as_tall() {
+    let fonts = [inconsolata()];
+    let (one_width, one_height) = measure_multiline(&fonts, "hello", 20.0, None);
+    let (two_width, two_height) = measure_multiline(&fonts, "hello\nhi", 20.0, None);
+
+    assert_eq!(one_width, two_width);
+    assert!((two_height - 2.0 * one_height).abs() < 0.001);
+}
+
+#[test]
+fn test_max_width_wraps() {
+    let fonts = [inconsolata()];
+    let (_, one_line) = measure_multiline(&fonts, "one two three", 20.0, None);
+    let (width, wrapped) = measure_multiline(&fonts, "one two three", 20.0, Some(50.0));
+
+    assert!(width <= 50.0);
+    assert!((wrapped - 3.0 * one_line).abs() < 0.001);
+}
@@ -0,0 +1,57 @@
+use wgpu_glyph::ab_glyph::{Font, ScaleFont};
+
+/// Vertical font metrics in pixels for a given scale. `descender` is
+/// negative, as it lies below the baseline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    pub ascender: f32,
+    pub descender: f32,
+    pub cap_height: f32,
+    pub x_height: f32,
+    pub line_gap: f32,
+    /// Unscaled, straight from the font header.
+    pub units_per_em: u16,
+}
+
+impl FontMetrics {
+    pub fn from_font<F: Font>(font: &F, scale: f32) -> Self {
+        let scaled = font.as_scaled(scale);
+        let v_scale = scaled.v_scale_factor();
+
+        // ab_glyph has no OS/2 cap/x height accessors, so measure the top of
+        // the 'H' and 'x' outlines instead. Outline bounds store the top edge
+        // in `min.y`.
+        let glyph_top = |c| {
+            font.outline(font.glyph_id(c))
+                .map_or(0.0, |outline| outline.bounds.min.y * v_scale)
+        };
+
+        Self {
+            ascender: scaled.ascent(),
+            descender: scaled.descent(),
+            cap_height: glyph_top('H'),
+            x_height: glyph_top('x'),
+            line_gap: scaled.line_gap(),
+            units_per_em: font.units_per_em().unwrap_or_default() as u16,
+        }
+    }
+
+    /// Distance from one baseline to the next.
+    pub fn line_height(&self) -> f32 {
+        self.ascender - self.descender + self.line_gap
+    }
+}
+
+#[test]
+fn test_font_metrics() {
+    let font =
+        wgpu_glyph::ab_glyph::FontRef::try_from_slice(include_bytes!("Inconsolata-Regular.ttf"))
+            .unwrap();
+    let metrics = FontMetrics::from_font(&font, 40.0);
+
+    assert_eq!(metrics.units_per_em, 1000);
+    assert!((metrics.ascender - metrics.descender - 40.0).abs() < 0.001);
+    assert!(metrics.x_height > 0.0);
+    assert!(metrics.x_height < metrics.cap_height);
+    assert!(metrics.cap_height < metrics.ascender);
+}
@@ -1,8 +1,61 @@
+mod animation;
+mod builder;
+mod contrast;
+mod error;
+mod font_info;
+mod fps;
+#[cfg(feature = "i18n")]
+mod i18n;
+mod measure;
+mod metrics;
+mod particles;
+mod path;
+#[cfg(feature = "3d-effects")]
+mod perspective;
+mod progress_bar;
+mod readback;
+mod stress;
+mod surface;
+mod text_buffer;
+mod text_change;
+#[cfg(feature = "timing")]
+mod timing;
+#[cfg(feature = "touch")]
+mod touch;
+mod transform;
+mod virtualized;
+
+pub use animation::{Animation, RainbowAnimation, SpringAnimation};
+pub use builder::GlyphyBuilder;
+pub use contrast::{meets_wcag_aa, wcag_contrast_ratio};
+pub use error::GlyphyError;
+pub use font_info::{FontInfo, FontSource};
+pub use fps::FpsCounterStyle;
+#[cfg(feature = "i18n")]
+pub use i18n::format_message;
+pub use metrics::FontMetrics;
+pub use particles::{CharParticle, ParticleExplosion};
+#[cfg(feature = "3d-effects")]
+pub use perspective::PerspectiveTransform;
+pub use progress_bar::ProgressBar;
+pub use readback::{copy_frame_to_buffer, padded_bytes_per_row, readback_pixel};
+pub use stress::cache_stress_workload;
+#[cfg(feature = "clipboard")]
+pub use text_buffer::ClipboardError;
+pub use text_buffer::{handle_key_event, TextBuffer};
+pub use text_change::{TextChangeCallback, TextChangeSlot};
+#[cfg(feature = "timing")]
+pub use timing::FrameTimings;
+#[cfg(feature = "touch")]
+pub use touch::{handle_touch_event, ViewState};
+pub use transform::Transform2D;
+
 use futures::executor::LocalSpawner;
 use futures::task::SpawnExt;
+use text_change::TextChangeTracker;
 use wgpu_glyph::{
-    ab_glyph::{self, InvalidFont},
-    GlyphBrush, GlyphBrushBuilder, Section, Text,
+    ab_glyph::{self, Font, ScaleFont},
+    FontId, GlyphBrush, GlyphBrushBuilder, Section, Text,
 };
 
 pub struct Glyphy {
@@ -10,6 +63,19 @@ pub struct Glyphy {
     local_pool: futures::executor::LocalPool,
     local_spawner: LocalSpawner,
     brush: GlyphBrush<()>,
+    // Indexed by FontId, like the brush's fonts.
+    font_infos: Vec<FontInfo>,
+    text_change: TextChangeTracker,
+    format: wgpu::TextureFormat,
+    atlas_size: (u32, u32),
+    present_mode: wgpu::PresentMode,
+    scale_factor: f32,
+    pixel_snapping: bool,
+    #[cfg(feature = "timing")]
+    timings: timing::TimingHistory,
+    // Labels for the encoder and clearing pass created by `render`.
+    encoder_label: String,
+    render_pass_label: String,
 }
 
 pub struct TextRenderable<'a> {
@@ -18,7 +84,7 @@ pub struct TextRenderable<'a> {
     pub scale: f32,
 }
 
-pub fn hex_str_to_rgba<'a>(s: &'a str) -> [f32; 4] {
+pub fn hex_str_to_rgba(s: &str) -> [f32; 4] {
     let re = regex::Regex::new(r"#([a-fA-F0-9]{6})").unwrap();
     if !re.is_match(s) {
         panic!("{} is not in hex format", s);
@@ -32,16 +98,16 @@ pub fn hex_str_to_rgba<'a>(s: &'a str) -> [f32; 4] {
         .collect::<Vec<String>>()
         .iter()
         .map(|chunk| {
-            hex::decode(chunk)
-                .expect(format!("unable to decode chuck {} in hex {}", chunk.as_str(), s).as_str())
-                [0] as f32
+            hex::decode(chunk).unwrap_or_else(|_| {
+                panic!("unable to decode chuck {} in hex {}", chunk.as_str(), s)
+            })[0] as f32
         })
         .collect();
 
     [rgb[0], rgb[1], rgb[2], 255.0]
 }
 
-pub fn hex_str_to_normalized_rgba<'a>(s: &'a str) -> [f32; 4] {
+pub fn hex_str_to_normalized_rgba(s: &str) -> [f32; 4] {
     let rgba = hex_str_to_rgba(s)
         .iter()
         .map(|v| v / 255.0)
@@ -50,6 +116,49 @@ pub fn hex_str_to_normalized_rgba<'a>(s: &'a str) -> [f32; 4] {
     [rgba[0], rgba[1], rgba[2], rgba[3]]
 }
 
+/// Converts a hue in degrees and saturation and lightness in `0.0..=1.0` to
+/// normalized rgba.
+pub fn hsl_to_normalized_rgba(h: f32, s: f32, l: f32) -> [f32; 4] {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+
+    [r + m, g + m, b + m, 1.0]
+}
+
+/// Decodes an sRGB-encoded channel in `0.0..=1.0` to linear light, using
+/// the IEC 61966-2-1 transfer function.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear channel in `0.0..=1.0` to sRGB. The inverse of
+/// `srgb_to_linear`.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn snap_to_pixel((x, y): (f32, f32)) -> (f32, f32) {
+    (x.round(), y.round())
+}
+
 #[test]
 #[should_panic]
 fn test_bad_hex_str_to_rgba() {
@@ -78,26 +187,218 @@ fn test_hex_str_to_normalized_rgba() {
     assert_eq!(rgba, [0.6862745, 0.27058825, 0.4509804, 1.0,])
 }
 
+#[test]
+fn test_hsl_to_normalized_rgba() {
+    assert_eq!(hsl_to_normalized_rgba(0.0, 1.0, 0.5), [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        hsl_to_normalized_rgba(120.0, 1.0, 0.5),
+        [0.0, 1.0, 0.0, 1.0]
+    );
+    assert_eq!(
+        hsl_to_normalized_rgba(240.0, 1.0, 0.5),
+        [0.0, 0.0, 1.0, 1.0]
+    );
+    assert_eq!(hsl_to_normalized_rgba(0.0, 0.0, 1.0), [1.0, 1.0, 1.0, 1.0]);
+}
+
+#[test]
+fn test_srgb_linear_round_trip() {
+    assert_eq!(srgb_to_linear(0.0), 0.0);
+    assert!((srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+    assert!((srgb_to_linear(0.5) - 0.21404).abs() < 1e-5);
+    for c in [0.0, 0.002, 0.04, 0.2, 0.5, 0.9, 1.0] {
+        assert!((linear_to_srgb(srgb_to_linear(c)) - c).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn test_snap_to_pixel() {
+    assert_eq!(snap_to_pixel((10.4, 20.5)), (10.0, 21.0));
+    assert_eq!(snap_to_pixel((-0.6, 3.0)), (-1.0, 3.0));
+}
+
 impl Glyphy {
-    pub fn init(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self, InvalidFont> {
+    /// A `Glyphy` with the bundled Inconsolata as its only font. Without the
+    /// `bundled-font` feature this returns `GlyphyError::NoFontConfigured`;
+    /// use `GlyphyBuilder` to supply fonts instead.
+    pub fn init(device: &wgpu::Device, format: wgpu::TextureFormat) -> Result<Self, GlyphyError> {
+        GlyphyBuilder::new().build(device, format)
+    }
+
+    /// A new `Glyphy` for `device` and `format` with the same fonts loaded
+    /// under the same `FontId`s. It shares font data with `self` and copies
+    /// its present mode, scale factor, pixel snapping and render labels. It
+    /// has its own glyph cache, staging belt, text change callback and frame
+    /// timings. The atlas keeps its size unless that exceeds the new
+    /// device's `max_texture_dimension_2d`, in which case it is clamped to
+    /// the limit.
+    pub fn clone_for_device(&self, device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        Self {
+            present_mode: self.present_mode,
+            scale_factor: self.scale_factor,
+            pixel_snapping: self.pixel_snapping,
+            encoder_label: self.encoder_label.clone(),
+            render_pass_label: self.render_pass_label.clone(),
+            ..Self::with_fonts(
+                device,
+                format,
+                self.brush.fonts().to_vec(),
+                self.font_infos.clone(),
+                builder::clamp_atlas_size(
+                    self.atlas_size,
+                    device.limits().max_texture_dimension_2d,
+                ),
+            )
+        }
+    }
+
+    fn with_fonts(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        fonts: Vec<ab_glyph::FontArc>,
+        font_infos: Vec<FontInfo>,
+        atlas_size: (u32, u32),
+    ) -> Self {
         // Create staging belt and a local pool
         let staging_belt = wgpu::util::StagingBelt::new(1024);
         let local_pool = futures::executor::LocalPool::new();
         let local_spawner = local_pool.spawner();
         // Prepare glyph_brush
-        let inconsolata =
-            ab_glyph::FontArc::try_from_slice(include_bytes!("Inconsolata-Regular.ttf"))?;
-        let brush = GlyphBrushBuilder::using_font(inconsolata).build(&device, format);
+        let brush = GlyphBrushBuilder::using_fonts(fonts)
+            .initial_cache_size(atlas_size)
+            .build(device, format);
 
-        Ok(Self {
+        Self {
             brush,
+            font_infos,
             staging_belt,
             local_pool,
             local_spawner,
-        })
+            text_change: TextChangeTracker::default(),
+            format,
+            atlas_size,
+            present_mode: wgpu::PresentMode::Mailbox,
+            scale_factor: 1.0,
+            pixel_snapping: false,
+            #[cfg(feature = "timing")]
+            timings: timing::TimingHistory::default(),
+            encoder_label: "Redraw".to_string(),
+            render_pass_label: "Render pass".to_string(),
+        }
+    }
+
+    /// The texture format to configure `surface` with and pass to `init`:
+    /// sRGB when the surface supports it, so colours are blended in linear
+    /// space. wgpu 0.12 only reports the surface's single preferred format,
+    /// so this is that format. Falls back to `Bgra8UnormSrgb` if the surface
+    /// is incompatible with `adapter`.
+    pub fn preferred_format(
+        surface: &wgpu::Surface,
+        adapter: &wgpu::Adapter,
+    ) -> wgpu::TextureFormat {
+        let formats: Vec<_> = surface.get_preferred_format(adapter).into_iter().collect();
+        surface::select_format(&formats).unwrap_or(wgpu::TextureFormat::Bgra8UnormSrgb)
+    }
+
+    /// Sets the ratio of physical to logical pixels, such as 2.0 on a HiDPI
+    /// display. Every drawing method treats positions, text scales and other
+    /// lengths as logical pixels and multiplies them by this. `size` stays in
+    /// physical pixels, as reported by the surface. Defaults to 1.0.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor as f32;
+    }
+
+    /// Rounds the position of each text, line or character drawn to whole
+    /// physical pixels, after the scale factor is applied, so glyphs are not
+    /// blurred by landing between texels. Rotated and transformed text has
+    /// only its origin rounded. Off by default.
+    pub fn set_pixel_snapping(&mut self, enabled: bool) {
+        self.pixel_snapping = enabled;
+    }
+
+    /// How long the phases of the most recent `render` took, or `None`
+    /// before the first frame.
+    #[cfg(feature = "timing")]
+    pub fn last_frame_timings(&self) -> Option<FrameTimings> {
+        self.timings.frames().last().copied()
+    }
+
+    /// Timings of the last 60 `render` calls, oldest first.
+    #[cfg(feature = "timing")]
+    pub fn frame_timing_history(&self) -> &[FrameTimings] {
+        self.timings.frames()
+    }
+
+    /// Sets the present mode used by `reconfigure_surface`. Defaults to
+    /// `Mailbox`.
+    pub fn set_present_mode(&mut self, present_mode: wgpu::PresentMode) {
+        self.present_mode = present_mode;
+    }
+
+    /// Configures `surface` at `width` x `height` with the format this
+    /// `Glyphy` was created for and its present mode. Call it once before
+    /// the first frame and again whenever the window is resized.
+    pub fn reconfigure_surface(
+        &self,
+        surface: &wgpu::Surface,
+        device: &wgpu::Device,
+        width: u32,
+        height: u32,
+    ) {
+        surface.configure(
+            device,
+            &wgpu::SurfaceConfiguration {
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                format: self.format,
+                width,
+                height,
+                present_mode: self.present_mode,
+            },
+        );
+    }
+
+    /// Registers `callback` to be called from `render` with the newly
+    /// rendered text whenever it differs from the previous frame. Texts are
+    /// joined with newlines. Returns the shared callback slot, which the
+    /// caller can use to replace or clear the callback later.
+    pub fn on_text_change(&mut self, callback: TextChangeCallback) -> TextChangeSlot {
+        self.text_change.set_callback(callback)
+    }
+
+    /// The text passed to the most recent `render`, joined with newlines.
+    pub fn last_rendered_text(&self) -> &str {
+        self.text_change.last_rendered_text()
+    }
+
+    /// Metrics of a loaded font at `scale` pixels. Panics if `font_id` was not
+    /// loaded by this `Glyphy`.
+    pub fn font_metrics(&self, font_id: FontId, scale: f32) -> FontMetrics {
+        FontMetrics::from_font(&self.brush.fonts()[font_id.0], scale)
+    }
+
+    /// Names and source of a loaded font. Panics if `font_id` was not loaded
+    /// by this `Glyphy`.
+    pub fn font_info(&self, font_id: FontId) -> FontInfo {
+        self.font_infos[font_id.0].clone()
+    }
+
+    /// Sets the labels that `render` gives its command encoder and render
+    /// pass, which GPU debuggers show. Defaults to "Redraw" and
+    /// "Render pass".
+    pub fn set_render_labels(&mut self, encoder: &str, pass: &str) {
+        self.encoder_label = encoder.to_string();
+        self.render_pass_label = pass.to_string();
+    }
+
+    /// The width of the widest line of `text` at `scale` in the default
+    /// font, and the total height of its lines. Lines are split on `\n` and,
+    /// if `max_width` is given, wrapped at word boundaries to fit it, as a
+    /// section with those bounds would be.
+    pub fn measure_multiline(&self, text: &str, scale: f32, max_width: Option<f32>) -> (f32, f32) {
+        measure::measure_multiline(self.brush.fonts(), text, scale, max_width)
     }
 
-    pub fn render<'a>(
+    pub fn render(
         &mut self,
         texts: Vec<TextRenderable>,
         device: &wgpu::Device,
@@ -106,14 +407,17 @@ impl Glyphy {
         view: &wgpu::TextureView,
         clear: bool,
     ) {
+        #[cfg(feature = "timing")]
+        let start = std::time::Instant::now();
+
         let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
-            label: Some("Redraw"),
+            label: Some(&self.encoder_label),
         });
 
         // Clear frame
         {
             let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Render pass"),
+                label: Some(&self.render_pass_label),
                 color_attachments: &[wgpu::RenderPassColorAttachment {
                     view,
                     resolve_target: None,
@@ -135,35 +439,552 @@ impl Glyphy {
             });
         }
 
+        let scale_factor = self.scale_factor;
         let mut offset_y = 0.0;
         let max_x = texts.iter().max_by_key(|t| t.text.len()).unwrap();
-        let offset_x = max_x.scale * 1.5 * max_x.text.len() as f32;
+        let offset_x = max_x.scale * scale_factor * 1.5 * max_x.text.len() as f32;
+
+        for text in texts.iter() {
+            let scale = text.scale * scale_factor;
+            let position = self.snap((size.0 as f32 - offset_x, 30.0 * scale_factor + offset_y));
+            self.brush.queue(Section {
+                screen_position: position,
+                bounds: (size.0 as f32, size.1 as f32),
+                text: vec![Text::new(text.text)
+                    .with_color(text.color)
+                    .with_scale(scale)],
+                ..Section::default()
+            });
+
+            offset_y += scale
+        }
+
+        self.draw_queued(&mut encoder, device, size, view);
+        #[cfg(feature = "timing")]
+        let encoded = std::time::Instant::now();
+        self.submit(encoder, queue);
+        #[cfg(feature = "timing")]
+        self.timings.push(timing::FrameTimings::from_instants(
+            start,
+            encoded,
+            std::time::Instant::now(),
+        ));
+
+        let rendered: Vec<&str> = texts.iter().map(|t| t.text).collect();
+        self.text_change.update(rendered.join("\n"));
+    }
+
+    /// Draws the lines of `lines` that fall inside a `view_height` tall
+    /// window scrolled `scroll_offset` pixels down the document, starting at
+    /// the top of `view`. `scroll_offset` is how far the content has
+    /// scrolled, as in `ViewState::scroll_offset`, so lines are drawn that
+    /// far up. Only the visible lines are queued, so the cost is the same for
+    /// ten lines or a million.
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_virtualized(
+        &mut self,
+        lines: &[String],
+        scale: f32,
+        color: [f32; 4],
+        scroll_offset: f32,
+        view_height: f32,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Virtualized text"),
+        });
+
+        let line_height = self.font_metrics(FontId(0), scale).line_height();
+        let physical_scale = scale * self.scale_factor;
+        for i in virtualized::visible_lines(lines.len(), line_height, scroll_offset, view_height) {
+            self.brush.queue(Section {
+                screen_position: self.to_physical((0.0, i as f32 * line_height - scroll_offset)),
+                bounds: (size.0 as f32, size.1 as f32),
+                text: vec![Text::new(&lines[i])
+                    .with_color(color)
+                    .with_scale(physical_scale)],
+                ..Section::default()
+            });
+        }
+
+        self.flush(encoder, device, queue, size, view);
+    }
 
-        for (i, text) in texts.iter().enumerate() {
+    /// Draws `fps` as "FPS: 60" at `position`, coloured by `style`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_fps_counter(
+        &mut self,
+        fps: f32,
+        position: (f32, f32),
+        scale: f32,
+        style: &FpsCounterStyle,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("FPS counter"),
+        });
+
+        let text = fps::fps_text(fps);
+        self.brush.queue(Section {
+            screen_position: self.to_physical(position),
+            bounds: (size.0 as f32, size.1 as f32),
+            text: vec![Text::new(&text)
+                .with_color(style.color(fps))
+                .with_scale(scale * self.scale_factor)],
+            ..Section::default()
+        });
+
+        self.flush(encoder, device, queue, size, view);
+    }
+
+    /// Draws `text` at `position` with a copy in `shadow_color` behind it,
+    /// moved by `shadow_offset`. The shadow is hard-edged: Glyphy has no blur
+    /// pass.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_shadow(
+        &mut self,
+        text: &TextRenderable,
+        position: (f32, f32),
+        shadow_color: [f32; 4],
+        shadow_offset: (f32, f32),
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Text shadow"),
+        });
+
+        let shadow_position = (position.0 + shadow_offset.0, position.1 + shadow_offset.1);
+        for (position, color) in [(shadow_position, shadow_color), (position, text.color)] {
             self.brush.queue(Section {
-                screen_position: (size.0 as f32 - offset_x as f32, 30.0 + offset_y),
+                screen_position: self.to_physical(position),
                 bounds: (size.0 as f32, size.1 as f32),
                 text: vec![Text::new(text.text)
+                    .with_color(color)
+                    .with_scale(text.scale * self.scale_factor)],
+                ..Section::default()
+            });
+        }
+
+        self.flush(encoder, device, queue, size, view);
+    }
+
+    /// Draws `bar` as a single line of text on top of the existing contents
+    /// of `view`.
+    pub fn draw_progress_bar(
+        &mut self,
+        bar: &ProgressBar,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Progress bar"),
+        });
+
+        let (filled, empty) = bar.strings();
+        let scale = bar.scale * self.scale_factor;
+        self.brush.queue(Section {
+            screen_position: self.to_physical(bar.position),
+            bounds: (size.0 as f32, size.1 as f32),
+            text: vec![
+                Text::new(&filled)
+                    .with_color(bar.filled_color)
+                    .with_scale(scale),
+                Text::new(&empty)
+                    .with_color(bar.empty_color)
+                    .with_scale(scale),
+            ],
+            ..Section::default()
+        });
+
+        self.flush(encoder, device, queue, size, view);
+    }
+
+    /// Draws `text` as a single line at `position`, coloured per character by
+    /// `rainbow`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rainbow_text(
+        &mut self,
+        text: &str,
+        rainbow: &RainbowAnimation,
+        position: (f32, f32),
+        scale: f32,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Rainbow text"),
+        });
+
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let colors = rainbow.colors(chars.len());
+        let scale = scale * self.scale_factor;
+        self.brush.queue(Section {
+            screen_position: self.to_physical(position),
+            bounds: (size.0 as f32, size.1 as f32),
+            text: chars
+                .iter()
+                .zip(colors)
+                .map(|((i, c), color)| {
+                    Text::new(&text[*i..*i + c.len_utf8()])
+                        .with_color(color)
+                        .with_scale(scale)
+                })
+                .collect(),
+            ..Section::default()
+        });
+
+        self.flush(encoder, device, queue, size, view);
+    }
+
+    /// Draws `text` along the polyline `path`, one character at a time, with
+    /// each character's baseline centred on the path and rotated to follow
+    /// it. Text that runs past the end of the path continues along the last
+    /// segment.
+    pub fn draw_text_on_path(
+        &mut self,
+        text: &TextRenderable,
+        path: &[(f32, f32)],
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let advances = self.char_advances(text);
+        let placements = path::place_along_path(path, &advances);
+
+        self.draw_placed_chars(
+            text,
+            "Text on path",
+            &advances,
+            &placements,
+            device,
+            queue,
+            size,
+            view,
+        );
+    }
+
+    /// Draws `text` around the circle at `center`, starting at
+    /// `start_angle_deg` and rotating each character to stay tangent to the
+    /// circle. Angles are measured clockwise from the positive x axis, so
+    /// -90 is the top of the circle. Clockwise text stands on the outside of
+    /// the circle; counter-clockwise text hangs inside it, reading left to
+    /// right along the bottom. Returns the arc consumed in degrees, so text
+    /// for the other half of a badge can be placed on the same circle.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_text_on_circle(
+        &mut self,
+        text: &TextRenderable,
+        center: (f32, f32),
+        radius: f32,
+        start_angle_deg: f32,
+        clockwise: bool,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) -> f32 {
+        let advances = self.char_advances(text);
+        let (placements, sweep) = path::place_along_arc(
+            center,
+            radius,
+            start_angle_deg.to_radians(),
+            clockwise,
+            &advances,
+        );
+
+        self.draw_placed_chars(
+            text,
+            "Text on circle",
+            &advances,
+            &placements,
+            device,
+            queue,
+            size,
+            view,
+        );
+
+        sweep.to_degrees()
+    }
+
+    /// Breaks `text` into one particle per character, laid out from the
+    /// baseline origin at `origin` with the same advances `render` uses.
+    /// `text.color` is ignored; `draw_particles` takes the colour.
+    pub fn explode_text(&self, text: &TextRenderable, origin: (f32, f32)) -> ParticleExplosion {
+        ParticleExplosion::new(text.text, &self.char_advances(text), origin, text.scale)
+    }
+
+    /// Draws every visible particle of `explosion` in `color`, with its
+    /// alpha scaled by the particle's.
+    pub fn draw_particles(
+        &mut self,
+        explosion: &ParticleExplosion,
+        color: [f32; 4],
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("Particles"),
+        });
+
+        let font = self.brush.fonts()[0].clone();
+        for particle in explosion.particles().iter().filter(|p| p.alpha > 0.0) {
+            let scale = particle.scale * self.scale_factor;
+            let scaled = font.as_scaled(scale);
+            let advance = scaled.h_advance(scaled.glyph_id(particle.ch));
+            let mut buf = [0; 4];
+            self.brush.queue(Section {
+                // Put the glyph's baseline centre at the transform origin.
+                screen_position: (-advance / 2.0, -scaled.ascent()),
+                text: vec![Text::new(particle.ch.encode_utf8(&mut buf))
+                    .with_color([color[0], color[1], color[2], color[3] * particle.alpha])
+                    .with_scale(scale)],
+                ..Section::default()
+            });
+            let position = self.to_physical(particle.position);
+            self.brush
+                .draw_queued_with_transform(
+                    device,
+                    &mut self.staging_belt,
+                    &mut encoder,
+                    view,
+                    path::glyph_transform(size, position, particle.rotation),
+                )
+                .expect("Draw queued");
+        }
+
+        self.submit(encoder, queue);
+    }
+
+    // Horizontal advance of each char of `text` in the default font.
+    fn char_advances(&self, text: &TextRenderable) -> Vec<f32> {
+        let scaled = self.brush.fonts()[0].as_scaled(text.scale);
+        text.text
+            .chars()
+            .map(|c| scaled.h_advance(scaled.glyph_id(c)))
+            .collect()
+    }
+
+    // Draws each char of `text` on its own, with its baseline centre moved to
+    // the matching placement's position and rotated by its angle. Advances
+    // and placements are in logical pixels.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_placed_chars(
+        &mut self,
+        text: &TextRenderable,
+        label: &str,
+        advances: &[f32],
+        placements: &[path::Placement],
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(label) });
+
+        let scale = text.scale * self.scale_factor;
+        let ascent = self.brush.fonts()[0].as_scaled(scale).ascent();
+        for ((c, advance), (position, angle)) in text.text.chars().zip(advances).zip(placements) {
+            let mut buf = [0; 4];
+            self.brush.queue(Section {
+                // Put the glyph's baseline centre at the transform origin.
+                screen_position: (-advance * self.scale_factor / 2.0, -ascent),
+                text: vec![Text::new(c.encode_utf8(&mut buf))
+                    .with_color(text.color)
+                    .with_scale(scale)],
+                ..Section::default()
+            });
+            let position = self.to_physical(*position);
+            self.brush
+                .draw_queued_with_transform(
+                    device,
+                    &mut self.staging_belt,
+                    &mut encoder,
+                    view,
+                    path::glyph_transform(size, position, *angle),
+                )
+                .expect("Draw queued");
+        }
+
+        self.submit(encoder, queue);
+    }
+
+    /// Draws each line of `text` with the matching entry of `transforms`
+    /// applied in pixels, relative to the line's top-left corner. Lines are
+    /// stacked `text.scale` apart from the top-left of `view`; lines past the
+    /// end of `transforms` are drawn untransformed.
+    pub fn render_with_line_transforms(
+        &mut self,
+        text: &TextRenderable,
+        transforms: &[Transform2D],
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        self.draw_lines(
+            text,
+            "Line transforms",
+            |i| {
+                let transform = transforms.get(i).copied().unwrap_or_default();
+                transform.then(&Transform2D::translate(0.0, i as f32 * text.scale))
+            },
+            device,
+            queue,
+            size,
+            view,
+        );
+    }
+
+    /// Draws `text` receding into the distance like a title crawl. The first
+    /// line sits at the bottom of `view`, and each later line is one line
+    /// further away, shrinking towards `vanishing_point`. `fov_deg` is the
+    /// vertical field of view; narrower fields flatten the effect.
+    #[cfg(feature = "3d-effects")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn render_with_perspective(
+        &mut self,
+        text: &TextRenderable,
+        fov_deg: f32,
+        vanishing_point: (f32, f32),
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let perspective = PerspectiveTransform {
+            fov_deg,
+            vanishing_point,
+            size: (
+                (size.0 as f32 / self.scale_factor).round() as u32,
+                (size.1 as f32 / self.scale_factor).round() as u32,
+            ),
+        };
+        self.draw_lines(
+            text,
+            "Perspective",
+            |i| perspective.line_transform(i, text.scale),
+            device,
+            queue,
+            size,
+            view,
+        );
+    }
+
+    // Draws each line of `text` on its own, with `line_transform(i)` mapping
+    // line `i` from its top-left corner to logical pixels.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_lines(
+        &mut self,
+        text: &TextRenderable,
+        label: &str,
+        line_transform: impl Fn(usize) -> Transform2D,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: Some(label) });
+
+        for (i, line) in text.text.lines().enumerate() {
+            self.brush.queue(Section {
+                text: vec![Text::new(line)
                     .with_color(text.color)
-                    .with_scale(text.scale)],
+                    .with_scale(text.scale * self.scale_factor)],
                 ..Section::default()
             });
+            // Glyphs are laid out at the physical scale, so only the
+            // translation needs converting. The scale factor is uniform and
+            // commutes with the rest of the transform.
+            let transform = line_transform(i);
+            let (tx, ty) = self.to_physical((transform.tx, transform.ty));
+            let transform = Transform2D {
+                tx,
+                ty,
+                ..transform
+            };
+            self.brush
+                .draw_queued_with_transform(
+                    device,
+                    &mut self.staging_belt,
+                    &mut encoder,
+                    view,
+                    transform.projection(size),
+                )
+                .expect("Draw queued");
+        }
+
+        self.submit(encoder, queue);
+    }
+
+    // Converts a logical position to physical pixels, snapped if pixel
+    // snapping is on.
+    fn to_physical(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        self.snap((x * self.scale_factor, y * self.scale_factor))
+    }
 
-            offset_y += text.scale
+    // Snaps a physical position to whole pixels if pixel snapping is on.
+    fn snap(&self, position: (f32, f32)) -> (f32, f32) {
+        if self.pixel_snapping {
+            snap_to_pixel(position)
+        } else {
+            position
         }
+    }
 
+    // Draws everything queued on the brush into `view` and submits `encoder`.
+    fn flush(
+        &mut self,
+        mut encoder: wgpu::CommandEncoder,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
+        self.draw_queued(&mut encoder, device, size, view);
+        self.submit(encoder, queue);
+    }
+
+    // Records drawing everything queued on the brush into `view`.
+    fn draw_queued(
+        &mut self,
+        encoder: &mut wgpu::CommandEncoder,
+        device: &wgpu::Device,
+        size: (u32, u32),
+        view: &wgpu::TextureView,
+    ) {
         // Draw the text!
         self.brush
             .draw_queued(
-                &device,
+                device,
                 &mut self.staging_belt,
-                &mut encoder,
+                encoder,
                 view,
                 size.0,
                 size.1,
             )
             .expect("Draw queued");
+    }
 
+    // Submits `encoder` and recalls the staging belt for the next frame.
+    fn submit(&mut self, encoder: wgpu::CommandEncoder, queue: &wgpu::Queue) {
         // Submit the work
         self.staging_belt.finish();
         queue.submit(Some(encoder.finish()));
@@ -0,0 +1,107 @@
+use super::Animation;
+use rand::Rng;
+
+/// Downward acceleration in pixels per second squared.
+const GRAVITY: f32 = 600.0;
+/// Fraction of velocity lost per second.
+const DRAG: f32 = 1.5;
+/// Seconds for a particle to fade from opaque to invisible.
+const FADE_SECONDS: f32 = 1.0;
+
+/// One character of a `ParticleExplosion`. `position` is the centre of the
+/// character's baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CharParticle {
+    pub ch: char,
+    pub position: (f32, f32),
+    pub velocity: (f32, f32),
+    /// Radians.
+    pub rotation: f32,
+    pub angular_velocity: f32,
+    pub alpha: f32,
+    pub scale: f32,
+}
+
+/// Characters flung apart from where they were laid out, falling and fading
+/// out over a second. Create it with `Glyphy::explode_text` and draw it with
+/// `Glyphy::draw_particles`.
+pub struct ParticleExplosion {
+    particles: Vec<CharParticle>,
+}
+
+impl ParticleExplosion {
+    /// One particle per character of `text`, laid out from the baseline
+    /// origin at `origin` with the given per-char `advances` and sent off in
+    /// a random direction. `Glyphy::explode_text` takes the advances from the
+    /// font.
+    pub(crate) fn new(text: &str, advances: &[f32], origin: (f32, f32), scale: f32) -> Self {
+        let mut rng = rand::thread_rng();
+        let mut x = origin.0;
+
+        let particles = text
+            .chars()
+            .zip(advances)
+            .map(|(ch, advance)| {
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                let speed = rng.gen_range(100.0..400.0);
+                let position = (x + advance / 2.0, origin.1);
+                x += advance;
+                CharParticle {
+                    ch,
+                    position,
+                    velocity: (speed * angle.cos(), speed * angle.sin()),
+                    rotation: 0.0,
+                    angular_velocity: rng.gen_range(-6.0..6.0),
+                    alpha: 1.0,
+                    scale,
+                }
+            })
+            .collect();
+
+        Self { particles }
+    }
+
+    pub fn particles(&self) -> &[CharParticle] {
+        &self.particles
+    }
+
+    /// Whether every particle has faded out.
+    pub fn is_finished(&self) -> bool {
+        self.particles.iter().all(|p| p.alpha <= 0.0)
+    }
+}
+
+impl Animation for ParticleExplosion {
+    fn update(&mut self, dt: f32) {
+        let drag = (1.0 - DRAG * dt).max(0.0);
+        for p in self.particles.iter_mut() {
+            p.velocity = (p.velocity.0 * drag, (p.velocity.1 + GRAVITY * dt) * drag);
+            p.position = (
+                p.position.0 + p.velocity.0 * dt,
+                p.position.1 + p.velocity.1 * dt,
+            );
+            p.rotation += p.angular_velocity * dt;
+            p.alpha = (p.alpha - dt / FADE_SECONDS).max(0.0);
+        }
+    }
+}
+
+#[test]
+fn test_particles_fall_and_fade() {
+    let mut explosion = ParticleExplosion::new("ab", &[10.0, 6.0], (10.0, 50.0), 20.0);
+    assert_eq!(explosion.particles()[0].position, (15.0, 50.0));
+    assert_eq!(explosion.particles()[1].position, (23.0, 50.0));
+
+    let before: Vec<f32> = explosion.particles().iter().map(|p| p.velocity.1).collect();
+    explosion.update(0.1);
+    for (p, vy) in explosion.particles().iter().zip(before) {
+        assert!(p.velocity.1 > vy * (1.0 - DRAG * 0.1));
+        assert!((p.alpha - 0.9).abs() < 1e-6);
+    }
+
+    assert!(!explosion.is_finished());
+    for _ in 0..10 {
+        explosion.update(0.1);
+    }
+    assert!(explosion.is_finished());
+}
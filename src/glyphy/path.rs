@@ -0,0 +1,137 @@
+/// A glyph's baseline centre and its rotation in radians.
+pub(crate) type Placement = ((f32, f32), f32);
+
+/// Walks `path` and returns, for each successive advance, the point at the
+/// middle of that advance and the path's angle there in radians. Distances
+/// past the end of the path extrapolate along the last segment.
+pub(crate) fn place_along_path(path: &[(f32, f32)], advances: &[f32]) -> Vec<Placement> {
+    let segments: Vec<_> = path
+        .windows(2)
+        .map(|w| (w[0], w[1], distance(w[0], w[1])))
+        .filter(|(_, _, len)| *len > 0.0)
+        .collect();
+
+    let mut placements = Vec::with_capacity(advances.len());
+    let mut distance_along = 0.0;
+
+    for advance in advances {
+        let target = distance_along + advance / 2.0;
+        distance_along += advance;
+
+        if segments.is_empty() {
+            let origin = path.first().copied().unwrap_or((0.0, 0.0));
+            placements.push(((origin.0 + target, origin.1), 0.0));
+            continue;
+        }
+
+        let mut start_of_segment = 0.0;
+        let mut placed = None;
+        for (i, (a, b, len)) in segments.iter().enumerate() {
+            if target <= start_of_segment + len || i == segments.len() - 1 {
+                let along = target - start_of_segment;
+                let position = (
+                    a.0 + (b.0 - a.0) / len * along,
+                    a.1 + (b.1 - a.1) / len * along,
+                );
+                placed = Some((position, (b.1 - a.1).atan2(b.0 - a.0)));
+                break;
+            }
+            start_of_segment += len;
+        }
+
+        placements.extend(placed);
+    }
+
+    placements
+}
+
+/// Places each advance along the circle around `center`, starting at
+/// `start_angle` radians and moving clockwise on screen or against it.
+/// Returns the placements, rotated to stand outside a clockwise circle or
+/// hang inside a counter-clockwise one, and the total angle swept.
+pub(crate) fn place_along_arc(
+    center: (f32, f32),
+    radius: f32,
+    start_angle: f32,
+    clockwise: bool,
+    advances: &[f32],
+) -> (Vec<Placement>, f32) {
+    let direction = if clockwise { 1.0 } else { -1.0 };
+    let mut swept = 0.0;
+
+    let placements = advances
+        .iter()
+        .map(|advance| {
+            let angle = start_angle + direction * (swept + advance / 2.0 / radius);
+            swept += advance / radius;
+
+            let (sin, cos) = angle.sin_cos();
+            let position = (center.0 + radius * cos, center.1 + radius * sin);
+            (position, angle + direction * std::f32::consts::FRAC_PI_2)
+        })
+        .collect();
+
+    (placements, swept)
+}
+
+/// Orthographic projection for a `size` target, with glyph space rotated by
+/// `angle` radians and then moved to `position`.
+pub(crate) fn glyph_transform(size: (u32, u32), position: (f32, f32), angle: f32) -> [f32; 16] {
+    let (sx, sy) = (2.0 / size.0 as f32, -2.0 / size.1 as f32);
+    let (sin, cos) = angle.sin_cos();
+
+    #[rustfmt::skip]
+    let transform = [
+        sx * cos, sy * sin, 0.0, 0.0,
+        -sx * sin, sy * cos, 0.0, 0.0,
+        0.0, 0.0, 1.0, 0.0,
+        sx * position.0 - 1.0, sy * position.1 + 1.0, 0.0, 1.0,
+    ];
+    transform
+}
+
+fn distance(a: (f32, f32), b: (f32, f32)) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+#[test]
+fn test_straight_path_matches_flat_layout() {
+    let placements = place_along_path(&[(0.0, 0.0), (100.0, 0.0)], &[10.0, 10.0, 20.0]);
+    assert_eq!(
+        placements,
+        vec![((5.0, 0.0), 0.0), ((15.0, 0.0), 0.0), ((30.0, 0.0), 0.0)]
+    );
+}
+
+#[test]
+fn test_path_corner() {
+    let placements = place_along_path(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)], &[10.0, 10.0]);
+    assert_eq!(placements[0], ((5.0, 0.0), 0.0));
+    assert_eq!(placements[1], ((10.0, 5.0), std::f32::consts::FRAC_PI_2));
+}
+
+#[test]
+fn test_arc_top_and_bottom() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    let quarter = 10.0 * FRAC_PI_2;
+    let (placements, swept) = place_along_arc((0.0, 0.0), 10.0, -PI, true, &[quarter, quarter]);
+    assert_eq!(swept, PI);
+    // Halfway along the clockwise arc is the top of the circle, upright.
+    let ((x, y), angle) = placements[0];
+    assert!((x + 7.071).abs() < 1e-3 && (y + 7.071).abs() < 1e-3);
+    assert!((angle + FRAC_PI_2 / 2.0).abs() < 1e-6);
+
+    let (placements, _) = place_along_arc((0.0, 0.0), 10.0, FRAC_PI_2, false, &[0.0]);
+    let ((x, y), angle) = placements[0];
+    assert!(x.abs() < 1e-3 && (y - 10.0).abs() < 1e-3);
+    assert!(angle.abs() < 1e-6);
+}
+
+#[test]
+fn test_unrotated_transform_is_orthographic() {
+    assert_eq!(
+        glyph_transform((800, 600), (0.0, 0.0), 0.0),
+        wgpu_glyph::orthographic_projection(800, 600)
+    );
+}
@@ -0,0 +1,51 @@
+use super::Transform2D;
+
+/// Lays lines of text on a plane that recedes from the bottom of the target
+/// towards `vanishing_point`, one line height further away per line.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerspectiveTransform {
+    pub fov_deg: f32,
+    pub vanishing_point: (f32, f32),
+    pub size: (u32, u32),
+}
+
+impl PerspectiveTransform {
+    /// How much line `line` shrinks at `line_height` pixels per line of
+    /// depth. The first line is at full size.
+    pub fn scale_factor(&self, line: usize, line_height: f32) -> f32 {
+        let focal_length = self.size.1 as f32 / 2.0 / (self.fov_deg.to_radians() / 2.0).tan();
+        focal_length / (focal_length + line as f32 * line_height)
+    }
+
+    /// Maps line `line` from its top-left corner to pixels.
+    pub fn line_transform(&self, line: usize, line_height: f32) -> Transform2D {
+        let scale = self.scale_factor(line, line_height);
+        let (vx, vy) = self.vanishing_point;
+
+        Transform2D::translate(0.0, self.size.1 as f32 - line_height)
+            .then(&Transform2D::translate(-vx, -vy))
+            .then(&Transform2D::scale(scale, scale))
+            .then(&Transform2D::translate(vx, vy))
+    }
+}
+
+#[test]
+fn test_far_lines_shrink_towards_vanishing_point() {
+    let perspective = PerspectiveTransform {
+        fov_deg: 60.0,
+        vanishing_point: (400.0, 100.0),
+        size: (800, 600),
+    };
+
+    assert_eq!(
+        perspective.line_transform(0, 20.0).apply((0.0, 0.0)),
+        (0.0, 580.0)
+    );
+
+    let near = perspective.scale_factor(1, 20.0);
+    let far = perspective.scale_factor(10, 20.0);
+    assert!(1.0 > near && near > far);
+
+    let (x, y) = perspective.line_transform(100_000, 20.0).apply((0.0, 0.0));
+    assert!((x - 400.0).abs() < 1.0 && (y - 100.0).abs() < 1.0);
+}
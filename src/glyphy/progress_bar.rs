@@ -0,0 +1,69 @@
+/// A progress bar drawn with text characters by `Glyphy::draw_progress_bar`.
+///
+/// `ProgressBar::new` uses `█` (U+2588) for the filled part and `░` (U+2591)
+/// for the empty part. The bundled Inconsolata has neither glyph, so pick
+/// other characters, such as `#` and `-`, unless a font with block elements
+/// is loaded.
+pub struct ProgressBar {
+    pub value: f32,
+    pub max: f32,
+    /// Total number of characters in the bar.
+    pub width: usize,
+    pub filled_char: char,
+    pub empty_char: char,
+    pub filled_color: [f32; 4],
+    pub empty_color: [f32; 4],
+    pub position: (f32, f32),
+    pub scale: f32,
+}
+
+impl ProgressBar {
+    pub fn new(value: f32, max: f32, width: usize) -> Self {
+        Self {
+            value,
+            max,
+            width,
+            filled_char: '█',
+            empty_char: '░',
+            filled_color: [1.0, 1.0, 1.0, 1.0],
+            empty_color: [0.5, 0.5, 0.5, 1.0],
+            position: (0.0, 0.0),
+            scale: 20.0,
+        }
+    }
+
+    /// Number of filled characters, `floor(width * value / max)` clamped to
+    /// `0..=width`.
+    pub fn filled(&self) -> usize {
+        if self.max <= 0.0 {
+            return 0;
+        }
+
+        let filled = (self.width as f32 * self.value / self.max).floor();
+        (filled.max(0.0) as usize).min(self.width)
+    }
+
+    /// The filled and empty parts of the bar.
+    pub fn strings(&self) -> (String, String) {
+        let filled = self.filled();
+        (
+            self.filled_char.to_string().repeat(filled),
+            self.empty_char.to_string().repeat(self.width - filled),
+        )
+    }
+}
+
+#[test]
+fn test_progress_bar_strings() {
+    let mut bar = ProgressBar::new(3.0, 10.0, 4);
+    bar.filled_char = '#';
+    bar.empty_char = '-';
+    assert_eq!(bar.strings(), ("#".to_string(), "---".to_string()));
+}
+
+#[test]
+fn test_progress_bar_clamps() {
+    assert_eq!(ProgressBar::new(20.0, 10.0, 4).filled(), 4);
+    assert_eq!(ProgressBar::new(-1.0, 10.0, 4).filled(), 0);
+    assert_eq!(ProgressBar::new(1.0, 0.0, 4).filled(), 0);
+}
@@ -0,0 +1,185 @@
+/// Reads back the texel at (`x`, `y`) of `texture` and returns its four
+/// bytes in the texture's own channel order, so a `Bgra8*` texture gives
+/// `[b, g, r, a]`. `texture` must have `COPY_SRC` usage and a 4-byte texel
+/// format. Blocks until the GPU has finished the copy.
+///
+/// A single texel is a single row, so the copy needs no `bytes_per_row`.
+/// Reading back more than one row at a time would need each row padded to
+/// `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT` (256) bytes in the buffer.
+pub fn readback_pixel(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    x: u32,
+    y: u32,
+) -> [u8; 4] {
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Readback pixel"),
+        size: 4,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Readback pixel"),
+    });
+    encoder.copy_texture_to_buffer(
+        wgpu::ImageCopyTexture {
+            texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d { x, y, z: 0 },
+            aspect: wgpu::TextureAspect::All,
+        },
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: None,
+                rows_per_image: None,
+            },
+        },
+        wgpu::Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit(Some(encoder.finish()));
+
+    let slice = buffer.slice(..);
+    let mapped = slice.map_async(wgpu::MapMode::Read);
+    device.poll(wgpu::Maintain::Wait);
+    futures::executor::block_on(mapped).expect("Map readback buffer");
+
+    let mut pixel = [0; 4];
+    pixel.copy_from_slice(&slice.get_mapped_range());
+    pixel
+}
+
+/// Bytes per row of a `width` texel wide, 4-byte-per-texel image copied
+/// into a buffer, padded up to `wgpu::COPY_BYTES_PER_ROW_ALIGNMENT`:
+/// `((width * 4 + 255) / 256) * 256`.
+pub fn padded_bytes_per_row(width: u32) -> u32 {
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    (width * 4).div_ceil(align) * align
+}
+
+/// Submits a copy of the whole `size` texture into a new `COPY_DST |
+/// MAP_READ` buffer and returns the buffer without waiting for it. Map it
+/// with `buffer.slice(..).map_async(wgpu::MapMode::Read)` and poll the
+/// device from your own loop. `texture` must have `COPY_SRC` usage and a
+/// 4-byte texel format.
+///
+/// Each row in the buffer is `padded_bytes_per_row(size.0)` bytes long, so
+/// skip the padding at the end of each row when reading texels out.
+pub fn copy_frame_to_buffer(
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    texture: &wgpu::Texture,
+    size: (u32, u32),
+) -> wgpu::Buffer {
+    let bytes_per_row = padded_bytes_per_row(size.0);
+    let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Frame readback"),
+        size: bytes_per_row as u64 * size.1 as u64,
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("Frame readback"),
+    });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::ImageCopyBuffer {
+            buffer: &buffer,
+            layout: wgpu::ImageDataLayout {
+                offset: 0,
+                bytes_per_row: std::num::NonZeroU32::new(bytes_per_row),
+                rows_per_image: None,
+            },
+        },
+        wgpu::Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        },
+    );
+    queue.submit(Some(encoder.finish()));
+
+    buffer
+}
+
+#[test]
+fn test_padded_bytes_per_row() {
+    assert_eq!(padded_bytes_per_row(1), 256);
+    assert_eq!(padded_bytes_per_row(64), 256);
+    assert_eq!(padded_bytes_per_row(65), 512);
+    assert_eq!(padded_bytes_per_row(800), 3328);
+}
+
+#[test]
+#[ignore = "needs a GPU adapter; run with `cargo test -- --ignored`"]
+fn test_readback_pixel_after_render() {
+    use super::{Glyphy, TextRenderable};
+
+    let instance = wgpu::Instance::new(wgpu::Backends::all());
+    let adapter = futures::executor::block_on(
+        instance.request_adapter(&wgpu::RequestAdapterOptions::default()),
+    )
+    .expect("Request adapter");
+    let (device, queue) = futures::executor::block_on(
+        adapter.request_device(&wgpu::DeviceDescriptor::default(), None),
+    )
+    .expect("Request device");
+
+    let size = (64, 64);
+    let format = wgpu::TextureFormat::Rgba8Unorm;
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width: size.0,
+            height: size.1,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    let mut glyphy = Glyphy::init(&device, format).unwrap();
+    let texts = vec![TextRenderable {
+        text: "#",
+        color: [1.0, 0.0, 0.0, 1.0],
+        scale: 40.0,
+    }];
+    glyphy.render(texts, &device, &queue, size, &view, true);
+
+    // Cleared to opaque black, and the corner is clear of any text.
+    assert_eq!(
+        readback_pixel(&device, &queue, &texture, 0, 63),
+        [0, 0, 0, 255]
+    );
+
+    let buffer = copy_frame_to_buffer(&device, &queue, &texture, size);
+    let slice = buffer.slice(..);
+    let mapped = slice.map_async(wgpu::MapMode::Read);
+    device.poll(wgpu::Maintain::Wait);
+    futures::executor::block_on(mapped).expect("Map frame buffer");
+
+    let data = slice.get_mapped_range();
+    let bytes_per_row = padded_bytes_per_row(size.0) as usize;
+    assert_eq!(data.len(), bytes_per_row * size.1 as usize);
+    // Texel (0, 63) starts row 63; the row padding comes after the texels.
+    let offset = 63 * bytes_per_row;
+    assert_eq!(&data[offset..offset + 4], &[0, 0, 0, 255]);
+
+    // Some texel of the red `#` was drawn.
+    assert!(data
+        .chunks(bytes_per_row)
+        .flat_map(|row| row[..size.0 as usize * 4].chunks(4))
+        .any(|texel| texel[0] > 0 && texel[1] == 0));
+}
@@ -0,0 +1,59 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+// Printable ASCII, from space to tilde.
+const CHARSET_LEN: usize = 95;
+
+/// `unique_strings` distinct pseudo-random strings of printable ASCII, each
+/// between 1 and `max_len` characters long. The same `seed` always produces
+/// the same workload, so cache behaviour can be compared between runs.
+/// Panics if there are fewer than `unique_strings` such strings.
+pub fn cache_stress_workload(unique_strings: usize, max_len: usize, seed: u64) -> Vec<String> {
+    let max_len = max_len.max(1);
+    let possible = (1..=max_len as u32)
+        .map(|len| CHARSET_LEN.saturating_pow(len))
+        .fold(0usize, usize::saturating_add);
+    if unique_strings > possible {
+        panic!(
+            "only {} distinct strings of up to {} characters, {} requested",
+            possible, max_len, unique_strings
+        );
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut seen = HashSet::new();
+    let mut workload = Vec::with_capacity(unique_strings);
+    while workload.len() < unique_strings {
+        let len = rng.gen_range(1..=max_len);
+        let s: String = (0..len).map(|_| rng.gen_range(' '..='~')).collect();
+        if seen.insert(s.clone()) {
+            workload.push(s);
+        }
+    }
+    workload
+}
+
+#[test]
+fn test_cache_stress_workload_is_seeded() {
+    let workload = cache_stress_workload(50, 8, 7);
+    assert_eq!(workload.len(), 50);
+    assert!(workload
+        .iter()
+        .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| (' '..='~').contains(&c))));
+    assert_eq!(workload.iter().collect::<HashSet<_>>().len(), 50);
+
+    assert_eq!(workload, cache_stress_workload(50, 8, 7));
+    assert_ne!(workload, cache_stress_workload(50, 8, 8));
+
+    let single_chars = cache_stress_workload(CHARSET_LEN, 1, 7);
+    assert_eq!(
+        single_chars.iter().collect::<HashSet<_>>().len(),
+        CHARSET_LEN
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_cache_stress_workload_more_than_possible() {
+    cache_stress_workload(CHARSET_LEN + 1, 1, 7);
+}
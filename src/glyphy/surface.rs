@@ -0,0 +1,20 @@
+/// The first sRGB format in `formats`, or failing that the first format.
+pub(crate) fn select_format(formats: &[wgpu::TextureFormat]) -> Option<wgpu::TextureFormat> {
+    formats
+        .iter()
+        .find(|format| format.describe().srgb)
+        .or_else(|| formats.first())
+        .copied()
+}
+
+#[test]
+fn test_select_format_prefers_srgb() {
+    use wgpu::TextureFormat::*;
+
+    assert_eq!(
+        select_format(&[Bgra8Unorm, Rgba8UnormSrgb, Bgra8UnormSrgb]),
+        Some(Rgba8UnormSrgb)
+    );
+    assert_eq!(select_format(&[Rgba16Float, Bgra8Unorm]), Some(Rgba16Float));
+    assert_eq!(select_format(&[]), None);
+}
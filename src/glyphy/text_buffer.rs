@@ -0,0 +1,256 @@
+use winit::event::{ElementState, KeyboardInput, VirtualKeyCode};
+
+pub struct TextBuffer {
+    content: String,
+    // Byte offset into `content`, always on a char boundary.
+    cursor: usize,
+    undo_stack: Vec<String>,
+    redo_stack: Vec<String>,
+    max_history: usize,
+}
+
+#[cfg(feature = "clipboard")]
+pub type ClipboardError = arboard::Error;
+
+impl TextBuffer {
+    pub fn new() -> Self {
+        Self {
+            content: String::new(),
+            cursor: 0,
+            undo_stack: vec![],
+            redo_stack: vec![],
+            max_history: 100,
+        }
+    }
+
+    /// Caps the number of undo checkpoints kept. The oldest are dropped first.
+    pub fn set_max_history(&mut self, max_history: usize) {
+        self.max_history = max_history;
+        self.trim_history();
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn insert_str(&mut self, s: &str) {
+        self.content.insert_str(self.cursor, s);
+        self.cursor += s.len();
+    }
+
+    /// Deletes the character before the cursor, like backspace.
+    pub fn delete_char(&mut self) {
+        if let Some((i, _)) = self.content[..self.cursor].char_indices().next_back() {
+            self.content.remove(i);
+            self.cursor = i;
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if let Some((i, _)) = self.content[..self.cursor].char_indices().next_back() {
+            self.cursor = i;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if let Some(c) = self.content[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    pub fn record_undo_checkpoint(&mut self) {
+        self.undo_stack.push(self.content.clone());
+        self.redo_stack.clear();
+        self.trim_history();
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(content) = self.undo_stack.pop() {
+            let current = std::mem::replace(&mut self.content, content);
+            self.redo_stack.push(current);
+            self.cursor = self.content.len();
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(content) = self.redo_stack.pop() {
+            let current = std::mem::replace(&mut self.content, content);
+            self.undo_stack.push(current);
+            self.cursor = self.content.len();
+        }
+    }
+
+    fn trim_history(&mut self) {
+        if self.undo_stack.len() > self.max_history {
+            let excess = self.undo_stack.len() - self.max_history;
+            self.undo_stack.drain(..excess);
+        }
+    }
+
+    /// Pastes the clipboard text at the cursor, recording an undo
+    /// checkpoint only if there is something to paste.
+    #[cfg(feature = "clipboard")]
+    pub fn paste_from_clipboard(&mut self) -> Result<(), ClipboardError> {
+        let text = arboard::Clipboard::new()?.get_text()?;
+        if !text.is_empty() {
+            self.record_undo_checkpoint();
+            self.insert_str(&text);
+        }
+        Ok(())
+    }
+}
+
+/// Applies the editing keys in `event` to `buffer`. Returns `true` if the
+/// event was consumed. Printable characters are not handled here; winit
+/// delivers them through `WindowEvent::ReceivedCharacter`.
+pub fn handle_key_event(event: &KeyboardInput, buffer: &mut TextBuffer) -> bool {
+    if event.state != ElementState::Pressed {
+        return false;
+    }
+
+    // winit 0.26 still carries the modifiers on the event itself.
+    #[allow(deprecated)]
+    let modifiers = event.modifiers;
+
+    match event.virtual_keycode {
+        Some(VirtualKeyCode::Back) => {
+            if buffer.cursor() > 0 {
+                buffer.record_undo_checkpoint();
+                buffer.delete_char();
+            }
+        }
+        Some(VirtualKeyCode::Return) => {
+            buffer.record_undo_checkpoint();
+            buffer.insert_str("\n");
+        }
+        Some(VirtualKeyCode::Left) => buffer.move_left(),
+        Some(VirtualKeyCode::Right) => buffer.move_right(),
+        Some(VirtualKeyCode::Z) if modifiers.ctrl() && modifiers.shift() => buffer.redo(),
+        Some(VirtualKeyCode::Z) if modifiers.ctrl() => buffer.undo(),
+        #[cfg(feature = "clipboard")]
+        Some(VirtualKeyCode::V) if modifiers.ctrl() => {
+            return buffer.paste_from_clipboard().is_ok();
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+impl Default for TextBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn test_insert_str() {
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("ab");
+    buffer.insert_str("cd");
+    assert_eq!(buffer.content(), "abcd");
+    assert_eq!(buffer.cursor(), 4);
+}
+
+#[test]
+fn test_delete_char() {
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("aé");
+    buffer.delete_char();
+    assert_eq!(buffer.content(), "a");
+    buffer.delete_char();
+    buffer.delete_char();
+    assert_eq!(buffer.content(), "");
+    assert_eq!(buffer.cursor(), 0);
+}
+
+#[cfg(test)]
+#[allow(deprecated)]
+fn key_press(key: VirtualKeyCode) -> KeyboardInput {
+    KeyboardInput {
+        scancode: 0,
+        state: ElementState::Pressed,
+        virtual_keycode: Some(key),
+        modifiers: Default::default(),
+    }
+}
+
+#[test]
+fn test_handle_key_event() {
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("ab");
+
+    assert!(handle_key_event(
+        &key_press(VirtualKeyCode::Left),
+        &mut buffer
+    ));
+    assert!(handle_key_event(
+        &key_press(VirtualKeyCode::Return),
+        &mut buffer
+    ));
+    assert_eq!(buffer.content(), "a\nb");
+
+    assert!(handle_key_event(
+        &key_press(VirtualKeyCode::Right),
+        &mut buffer
+    ));
+    assert!(handle_key_event(
+        &key_press(VirtualKeyCode::Back),
+        &mut buffer
+    ));
+    assert_eq!(buffer.content(), "a\n");
+
+    assert!(!handle_key_event(
+        &key_press(VirtualKeyCode::A),
+        &mut buffer
+    ));
+}
+
+#[test]
+fn test_undo_redo() {
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("hello");
+    buffer.record_undo_checkpoint();
+    buffer.insert_str(" world");
+
+    buffer.undo();
+    assert_eq!(buffer.content(), "hello");
+    buffer.redo();
+    assert_eq!(buffer.content(), "hello world");
+}
+
+#[test]
+fn test_backspace_at_start_keeps_undo_history() {
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("a");
+    buffer.record_undo_checkpoint();
+    buffer.insert_str("b");
+    buffer.move_left();
+    buffer.move_left();
+
+    assert!(handle_key_event(
+        &key_press(VirtualKeyCode::Back),
+        &mut buffer
+    ));
+    buffer.undo();
+    assert_eq!(buffer.content(), "a");
+}
+
+#[test]
+fn test_max_history() {
+    let mut buffer = TextBuffer::new();
+    buffer.set_max_history(2);
+    for s in ["a", "b", "c"] {
+        buffer.record_undo_checkpoint();
+        buffer.insert_str(s);
+    }
+
+    buffer.undo();
+    buffer.undo();
+    buffer.undo();
+    assert_eq!(buffer.content(), "a");
+}
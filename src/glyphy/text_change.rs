@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+pub type TextChangeCallback = Arc<dyn Fn(&str) + Send + Sync>;
+pub type TextChangeSlot = Arc<Mutex<Option<TextChangeCallback>>>;
+
+/// Remembers the last rendered text and tells the registered callback when
+/// a frame renders something different.
+#[derive(Default)]
+pub(crate) struct TextChangeTracker {
+    last_rendered_text: String,
+    callback: TextChangeSlot,
+}
+
+impl TextChangeTracker {
+    /// Shared handle to the callback slot, so the caller's update loop can
+    /// swap it out without going through `Glyphy`.
+    pub(crate) fn set_callback(&mut self, callback: TextChangeCallback) -> TextChangeSlot {
+        *self.callback.lock().unwrap() = Some(callback);
+        Arc::clone(&self.callback)
+    }
+
+    pub(crate) fn last_rendered_text(&self) -> &str {
+        &self.last_rendered_text
+    }
+
+    pub(crate) fn update(&mut self, text: String) {
+        if text == self.last_rendered_text {
+            return;
+        }
+
+        self.last_rendered_text = text;
+        // Called with the lock released, so the callback can replace or
+        // clear itself through the shared slot.
+        let callback = self.callback.lock().unwrap().clone();
+        if let Some(callback) = callback {
+            callback(&self.last_rendered_text);
+        }
+    }
+}
+
+#[test]
+fn test_callback_fires_once_per_change() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let mut tracker = TextChangeTracker::default();
+    tracker.set_callback(Arc::new(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    }));
+
+    tracker.update("a".to_string());
+    tracker.update("a".to_string());
+    tracker.update("b".to_string());
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(tracker.last_rendered_text(), "b");
+}
+
+#[test]
+fn test_callback_can_clear_itself() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&calls);
+    let slot: Arc<Mutex<Option<TextChangeSlot>>> = Default::default();
+    let callback_slot = Arc::clone(&slot);
+    let mut tracker = TextChangeTracker::default();
+    let shared = tracker.set_callback(Arc::new(move |_| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        if let Some(shared) = callback_slot.lock().unwrap().as_ref() {
+            *shared.lock().unwrap() = None;
+        }
+    }));
+    *slot.lock().unwrap() = Some(shared);
+
+    tracker.update("a".to_string());
+    tracker.update("b".to_string());
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,70 @@
+use std::time::Instant;
+
+/// Frames kept by `Glyphy::frame_timing_history`.
+const HISTORY_LEN: usize = 60;
+
+/// How long the phases of one `Glyphy::render` call took, in milliseconds.
+/// `encode_ms` covers queueing text and recording the draw, `submit_ms`
+/// covers handing the commands to the queue and recalling the staging belt.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameTimings {
+    pub encode_ms: f32,
+    pub submit_ms: f32,
+    pub total_ms: f32,
+}
+
+impl FrameTimings {
+    pub(crate) fn from_instants(start: Instant, encoded: Instant, submitted: Instant) -> Self {
+        let ms = |from: Instant, to: Instant| (to - from).as_secs_f32() * 1000.0;
+        Self {
+            encode_ms: ms(start, encoded),
+            submit_ms: ms(encoded, submitted),
+            total_ms: ms(start, submitted),
+        }
+    }
+}
+
+/// The most recent `HISTORY_LEN` frames, oldest first.
+#[derive(Default)]
+pub(crate) struct TimingHistory {
+    frames: Vec<FrameTimings>,
+}
+
+impl TimingHistory {
+    pub(crate) fn push(&mut self, timings: FrameTimings) {
+        if self.frames.len() == HISTORY_LEN {
+            self.frames.remove(0);
+        }
+        self.frames.push(timings);
+    }
+
+    pub(crate) fn frames(&self) -> &[FrameTimings] {
+        &self.frames
+    }
+}
+
+#[test]
+fn test_total_covers_phases() {
+    let start = Instant::now();
+    let encoded = start + std::time::Duration::from_micros(1500);
+    let submitted = encoded + std::time::Duration::from_micros(250);
+    let timings = FrameTimings::from_instants(start, encoded, submitted);
+
+    assert!((timings.encode_ms - 1.5).abs() < 1e-4);
+    assert!((timings.submit_ms - 0.25).abs() < 1e-4);
+    assert!(timings.total_ms >= timings.encode_ms + timings.submit_ms - 1e-4);
+}
+
+#[test]
+fn test_history_keeps_last_frames() {
+    let mut history = TimingHistory::default();
+    for i in 0..HISTORY_LEN + 5 {
+        history.push(FrameTimings {
+            total_ms: i as f32,
+            ..FrameTimings::default()
+        });
+    }
+
+    assert_eq!(history.frames().len(), HISTORY_LEN);
+    assert_eq!(history.frames()[0].total_ms, 5.0);
+}
@@ -0,0 +1,129 @@
+use std::collections::BTreeMap;
+use winit::event::{Touch, TouchPhase};
+
+/// Scroll and zoom of a text view, driven by `handle_touch_event`. Glyphy
+/// does not apply these itself: subtract `scroll_offset` from text
+/// positions and multiply scales by `zoom` when drawing. `scroll_offset` is
+/// how far the content has scrolled, so dragging a finger up scrolls down
+/// and `scroll_offset.1` can be passed straight to
+/// `Glyphy::render_virtualized`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewState {
+    pub scroll_offset: (f32, f32),
+    pub zoom: f32,
+    // Fingers currently down, by touch id.
+    touches: BTreeMap<u64, (f32, f32)>,
+}
+
+impl ViewState {
+    pub fn new() -> Self {
+        Self {
+            scroll_offset: (0.0, 0.0),
+            zoom: 1.0,
+            touches: BTreeMap::new(),
+        }
+    }
+
+    // Centre of, and distance between, the first two fingers down.
+    fn pinch(&self) -> Option<((f32, f32), f32)> {
+        let mut fingers = self.touches.values();
+        let (a, b) = (fingers.next()?, fingers.next()?);
+        let centre = ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+        Some((centre, ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()))
+    }
+}
+
+impl Default for ViewState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Updates `view_state` from a touch event. One finger pans. Two fingers
+/// scroll by the movement of their centre and zoom by the change in their
+/// distance. Returns `true` if the view changed.
+pub fn handle_touch_event(touch: &Touch, view_state: &mut ViewState) -> bool {
+    let location = (touch.location.x as f32, touch.location.y as f32);
+
+    match touch.phase {
+        TouchPhase::Started => {
+            view_state.touches.insert(touch.id, location);
+            false
+        }
+        TouchPhase::Ended | TouchPhase::Cancelled => {
+            view_state.touches.remove(&touch.id);
+            false
+        }
+        TouchPhase::Moved => {
+            let before = view_state.pinch();
+            let previous = match view_state.touches.insert(touch.id, location) {
+                Some(previous) => previous,
+                None => return false,
+            };
+
+            let (delta, zoom) = match (before, view_state.pinch()) {
+                (Some((centre, distance)), Some((new_centre, new_distance))) => (
+                    (new_centre.0 - centre.0, new_centre.1 - centre.1),
+                    if distance > 0.0 {
+                        new_distance / distance
+                    } else {
+                        1.0
+                    },
+                ),
+                _ => ((location.0 - previous.0, location.1 - previous.1), 1.0),
+            };
+
+            // The content follows the finger, so it scrolls the other way.
+            view_state.scroll_offset.0 -= delta.0;
+            view_state.scroll_offset.1 -= delta.1;
+            view_state.zoom *= zoom;
+            delta != (0.0, 0.0) || zoom != 1.0
+        }
+    }
+}
+
+#[cfg(test)]
+fn touch(id: u64, phase: TouchPhase, x: f64, y: f64) -> Touch {
+    Touch {
+        device_id: unsafe { winit::event::DeviceId::dummy() },
+        phase,
+        location: winit::dpi::PhysicalPosition::new(x, y),
+        force: None,
+        id,
+    }
+}
+
+#[test]
+fn test_one_finger_pans() {
+    let mut view = ViewState::new();
+    assert!(!handle_touch_event(
+        &touch(1, TouchPhase::Started, 10.0, 10.0),
+        &mut view
+    ));
+    assert!(handle_touch_event(
+        &touch(1, TouchPhase::Moved, 15.0, 30.0),
+        &mut view
+    ));
+    assert_eq!(view.scroll_offset, (-5.0, -20.0));
+    assert_eq!(view.zoom, 1.0);
+
+    handle_touch_event(&touch(1, TouchPhase::Ended, 15.0, 30.0), &mut view);
+    assert!(!handle_touch_event(
+        &touch(1, TouchPhase::Moved, 50.0, 50.0),
+        &mut view
+    ));
+}
+
+#[test]
+fn test_two_fingers_pinch_zoom() {
+    let mut view = ViewState::new();
+    handle_touch_event(&touch(1, TouchPhase::Started, 0.0, 0.0), &mut view);
+    handle_touch_event(&touch(2, TouchPhase::Started, 10.0, 0.0), &mut view);
+
+    assert!(handle_touch_event(
+        &touch(2, TouchPhase::Moved, 20.0, 0.0),
+        &mut view
+    ));
+    assert_eq!(view.zoom, 2.0);
+    assert_eq!(view.scroll_offset, (-5.0, 0.0));
+}
@@ -0,0 +1,109 @@
+/// A 2D affine transform in pixels, mapping `(x, y)` to
+/// `(a * x + c * y + tx, b * x + d * y + ty)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Transform2D {
+    pub fn identity() -> Self {
+        Self::scale(1.0, 1.0)
+    }
+
+    pub fn scale(sx: f32, sy: f32) -> Self {
+        Self {
+            a: sx,
+            b: 0.0,
+            c: 0.0,
+            d: sy,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    /// Moves x by `kx` per pixel of y, and y by `ky` per pixel of x.
+    pub fn shear(kx: f32, ky: f32) -> Self {
+        Self {
+            a: 1.0,
+            b: ky,
+            c: kx,
+            d: 1.0,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
+    pub fn translate(tx: f32, ty: f32) -> Self {
+        Self {
+            tx,
+            ty,
+            ..Self::identity()
+        }
+    }
+
+    /// Applies `self` first, then `other`.
+    pub fn then(&self, other: &Transform2D) -> Self {
+        Self {
+            a: other.a * self.a + other.c * self.b,
+            b: other.b * self.a + other.d * self.b,
+            c: other.a * self.c + other.c * self.d,
+            d: other.b * self.c + other.d * self.d,
+            tx: other.a * self.tx + other.c * self.ty + other.tx,
+            ty: other.b * self.tx + other.d * self.ty + other.ty,
+        }
+    }
+
+    pub fn apply(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (
+            self.a * x + self.c * y + self.tx,
+            self.b * x + self.d * y + self.ty,
+        )
+    }
+
+    /// Orthographic projection for a `size` target, with pixel space
+    /// transformed by `self` first.
+    pub(crate) fn projection(&self, size: (u32, u32)) -> [f32; 16] {
+        let (sx, sy) = (2.0 / size.0 as f32, -2.0 / size.1 as f32);
+
+        #[rustfmt::skip]
+        let transform = [
+            sx * self.a, sy * self.b, 0.0, 0.0,
+            sx * self.c, sy * self.d, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            sx * self.tx - 1.0, sy * self.ty + 1.0, 0.0, 1.0,
+        ];
+        transform
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[test]
+fn test_identity_projection_is_orthographic() {
+    assert_eq!(
+        Transform2D::identity().projection((800, 600)),
+        wgpu_glyph::orthographic_projection(800, 600)
+    );
+}
+
+#[test]
+fn test_scale_doubles_line_width() {
+    let line = Transform2D::scale(2.0, 1.0).then(&Transform2D::translate(0.0, 20.0));
+    assert_eq!(line.apply((0.0, 0.0)), (0.0, 20.0));
+    assert_eq!(line.apply((50.0, 0.0)), (100.0, 20.0));
+}
+
+#[test]
+fn test_then_applies_in_order() {
+    let t = Transform2D::translate(10.0, 0.0).then(&Transform2D::scale(2.0, 3.0));
+    assert_eq!(t.apply((1.0, 1.0)), (22.0, 3.0));
+}
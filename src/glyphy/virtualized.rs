@@ -0,0 +1,30 @@
+use std::ops::Range;
+
+/// Indices of the lines that overlap a `view_height` tall window scrolled
+/// `scroll_offset` pixels down a document of `line_count` lines, each
+/// `line_height` pixels tall.
+pub(crate) fn visible_lines(
+    line_count: usize,
+    line_height: f32,
+    scroll_offset: f32,
+    view_height: f32,
+) -> Range<usize> {
+    if line_height <= 0.0 {
+        return 0..0;
+    }
+
+    let first = (scroll_offset.max(0.0) / line_height) as usize;
+    let last = ((scroll_offset.max(0.0) + view_height.max(0.0)) / line_height).ceil() as usize;
+    first.min(line_count)..last.min(line_count)
+}
+
+#[test]
+fn test_visible_lines() {
+    assert_eq!(visible_lines(1_000_000, 20.0, 0.0, 100.0), 0..5);
+    // Line 5 is at 85..105 on screen, partly inside the view.
+    assert_eq!(visible_lines(1_000_000, 20.0, 15.0, 95.0), 0..6);
+    assert_eq!(visible_lines(1_000_000, 20.0, 450.0, 100.0), 22..28);
+    assert_eq!(visible_lines(25, 20.0, 450.0, 100.0), 22..25);
+    assert_eq!(visible_lines(10, 20.0, 450.0, 100.0), 10..10);
+    assert_eq!(visible_lines(10, 20.0, -30.0, 100.0), 0..5);
+}
@@ -1,5 +1,7 @@
-mod glyphy;
-use crate::glyphy::{hex_str_to_normalized_rgba, Glyphy, TextRenderable};
+use glyph_test::glyphy::{
+    handle_key_event, hex_str_to_normalized_rgba, srgb_to_linear, Glyphy, TextBuffer,
+    TextRenderable,
+};
 use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -15,7 +17,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let instance = wgpu::Instance::new(wgpu::Backends::all());
     let surface = unsafe { instance.create_surface(&window) };
 
-    let (device, queue) = futures::executor::block_on(async {
+    let (adapter, device, queue) = futures::executor::block_on(async {
         let adapter = instance
             .request_adapter(&wgpu::RequestAdapterOptions {
                 power_preference: wgpu::PowerPreference::HighPerformance,
@@ -25,31 +27,25 @@ fn main() -> Result<(), Box<dyn Error>> {
             .await
             .expect("Request adapter");
 
-        adapter
+        let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor::default(), None)
             .await
-            .expect("Request device")
+            .expect("Request device");
+
+        (adapter, device, queue)
     });
 
     let mut size = window.inner_size();
 
-    let render_format = wgpu::TextureFormat::Bgra8UnormSrgb;
-    surface.configure(
-        &device,
-        &wgpu::SurfaceConfiguration {
-            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-            format: render_format,
-            width: size.width,
-            height: size.height,
-            present_mode: wgpu::PresentMode::Mailbox,
-        },
-    );
-
+    let render_format = Glyphy::preferred_format(&surface, &adapter);
     let mut glyphy = Glyphy::init(&device, render_format).unwrap();
+    glyphy.set_scale_factor(window.scale_factor());
+    glyphy.reconfigure_surface(&surface, &device, size.width, size.height);
 
     window.request_redraw();
 
-    let mut text = "a: vec![#dd1133]";
+    let mut buffer = TextBuffer::new();
+    buffer.insert_str("a: vec![#dd1133]");
 
     event_loop.run(move |event, _, control_flow| match event {
         winit::event::Event::WindowEvent {
@@ -61,20 +57,43 @@ fn main() -> Result<(), Box<dyn Error>> {
             ..
         } => {
             size = new_size;
-
-            surface.configure(
-                &device,
-                &wgpu::SurfaceConfiguration {
-                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
-                    format: render_format,
-                    width: size.width,
-                    height: size.height,
-                    present_mode: wgpu::PresentMode::Mailbox,
+            glyphy.reconfigure_surface(&surface, &device, size.width, size.height);
+        }
+        winit::event::Event::WindowEvent {
+            event:
+                winit::event::WindowEvent::ScaleFactorChanged {
+                    scale_factor,
+                    new_inner_size,
                 },
-            );
+            ..
+        } => {
+            size = *new_inner_size;
+            glyphy.set_scale_factor(scale_factor);
+            glyphy.reconfigure_surface(&surface, &device, size.width, size.height);
+        }
+        winit::event::Event::WindowEvent {
+            event: winit::event::WindowEvent::ReceivedCharacter(c),
+            ..
+        } => {
+            // winit 0.26 only reports the produced text through
+            // ReceivedCharacter, so printable input is read from there.
+            if !c.is_control() {
+                buffer.record_undo_checkpoint();
+                buffer.insert_str(c.encode_utf8(&mut [0; 4]));
+                window.request_redraw();
+            }
+        }
+        winit::event::Event::WindowEvent {
+            event: winit::event::WindowEvent::KeyboardInput { input, .. },
+            ..
+        } => {
+            if handle_key_event(&input, &mut buffer) {
+                window.request_redraw();
+            }
         }
         winit::event::Event::RedrawRequested { .. } => {
             let frame = surface.get_current_texture().expect("Get next frame");
+            let text = buffer.content();
             let view = &frame
                 .texture
                 .create_view(&wgpu::TextureViewDescriptor::default());
@@ -82,17 +101,17 @@ fn main() -> Result<(), Box<dyn Error>> {
             let texts = vec![
                 // TextRenderable {
                 // text,
-                // color: hex_str_to_normalized_rgba("#af4573"),
+                // color: hex_color("#af4573", render_format),
                 // scale: 80.0,
                 // },
                 TextRenderable {
                     text,
-                    color: hex_str_to_normalized_rgba("#fdfead"),
+                    color: hex_color("#fdfead", render_format),
                     scale: 40.0,
                 },
                 TextRenderable {
                     text,
-                    color: hex_str_to_normalized_rgba("#fdaaad"),
+                    color: hex_color("#fdaaad", render_format),
                     scale: 20.0,
                 },
             ];
@@ -112,3 +131,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     })
 }
+
+// Hex codes are sRGB-encoded, but an *Srgb target expects linear colours.
+fn hex_color(hex: &str, format: wgpu::TextureFormat) -> [f32; 4] {
+    let [r, g, b, a] = hex_str_to_normalized_rgba(hex);
+    if format.describe().srgb {
+        [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+    } else {
+        [r, g, b, a]
+    }
+}